@@ -484,6 +484,97 @@ ast_enum_of_structs! {
 /// ```
 pub type AttributeArgs = Vec<NestedMeta>;
 
+/// Concatenates the documentation in a list of attributes into one string.
+///
+/// Every `///` line of a doc comment becomes a separate `#[doc = "..."]`
+/// attribute. This joins the string values of all such attributes with
+/// newlines, removing one leading space from each line, so that the result
+/// reads like the original comment. Attributes other than `#[doc = "..."]`,
+/// including `#[doc(hidden)]`, are skipped.
+///
+/// *This function is available if Syn is built with the `"parsing"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{parse_quote, ItemStruct};
+///
+/// let item: ItemStruct = parse_quote! {
+///     /// A point.
+///     #[derive(Copy, Clone)]
+///     ///
+///     /// Both coordinates are in pixels.
+///     struct Point(u32, u32);
+/// };
+///
+/// let doc = syn::collect_doc(&item.attrs);
+/// assert_eq!(doc, "A point.\n\nBoth coordinates are in pixels.");
+/// ```
+#[cfg(feature = "parsing")]
+pub fn collect_doc(attrs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident("doc") {
+            continue;
+        }
+        if let Ok(Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(lit), ..
+        })) = attr.parse_meta()
+        {
+            for line in lit.value().split('\n') {
+                let line = if line.starts_with(' ') {
+                    &line[1..]
+                } else {
+                    line
+                };
+                lines.push(line.to_owned());
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+/// Whether an item with the given attributes and visibility is part of its
+/// crate's documented public API.
+///
+/// This is the case if the visibility is `pub` and there is no
+/// `#[doc(hidden)]` attribute. Restricted visibilities like `pub(crate)` do
+/// not count as public.
+///
+/// *This function is available if Syn is built with the `"parsing"` feature.*
+#[cfg(feature = "parsing")]
+pub fn is_public_api(attrs: &[Attribute], vis: &Visibility) -> bool {
+    match vis {
+        Visibility::Public(_) => {}
+        _ => return false,
+    }
+
+    !attrs.iter().any(|attr| {
+        if !attr.path.is_ident("doc") {
+            return false;
+        }
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested.iter().any(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.is_ident("hidden"),
+                _ => false,
+            }),
+            _ => false,
+        }
+    })
+}
+
+/// Whether the attributes include `#[async_trait]`, written either bare or
+/// with a path like `#[async_trait::async_trait]`.
+///
+/// Trait methods under such an attribute are written with the `async` keyword
+/// but are rewritten by the attribute macro to return a boxed future.
+pub fn has_async_trait_attr(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| match attr.path.segments.last() {
+        Some(segment) => segment.ident == "async_trait",
+        None => false,
+    })
+}
+
 pub trait FilterAttrs<'a> {
     type Ret: Iterator<Item = &'a Attribute>;
 
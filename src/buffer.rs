@@ -360,3 +360,19 @@ pub(crate) fn close_span_of_group(cursor: Cursor) -> Span {
         _ => cursor.span(),
     }
 }
+
+// The span of the last token from `begin` up to but excluding `end`, where a
+// group ends at its closing delimiter. Falls back to the span of `end` if
+// there is no token in between.
+pub(crate) fn last_span_before(begin: Cursor, end: Cursor) -> Span {
+    let mut span = end.span();
+    let mut cursor = begin;
+    while cursor != end {
+        span = close_span_of_group(cursor);
+        cursor = match cursor.token_tree() {
+            Some((_tt, next)) => next,
+            None => break,
+        };
+    }
+    span
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::cell::UnsafeCell;
+
+/// An [`Item`] together with its printed tokens, which are computed the first
+/// time they are needed and reused until the item is mutated.
+///
+/// This saves re-running [`ToTokens`] on unchanged items in code generators
+/// that print the same items many times. The `ToTokens` impl fills the cache
+/// in the same way and then clones the cached tokens.
+///
+/// *This type is available if Syn is built with the `"full"` and `"printing"`
+/// features.*
+///
+/// # Example
+///
+/// ```
+/// use quote::ToTokens;
+/// use syn::{parse_quote, CachedItem};
+///
+/// let mut cached = CachedItem::new(parse_quote!(fn f() {}));
+/// assert!(!cached.is_cached());
+/// assert_eq!(cached.tokens().to_string(), "fn f () { }");
+/// assert!(cached.is_cached());
+/// assert_eq!(cached.to_token_stream().to_string(), "fn f () { }");
+///
+/// cached.item_mut().make_pub();
+/// assert!(!cached.is_cached());
+/// assert_eq!(cached.into_token_stream().to_string(), "pub fn f () { }");
+/// ```
+pub struct CachedItem {
+    item: Item,
+    // Only ever written while it is `None`, or through `&mut self`, so the
+    // references returned by `tokens` stay valid for as long as they live.
+    tokens: UnsafeCell<Option<TokenStream>>,
+}
+
+impl CachedItem {
+    pub fn new(item: Item) -> Self {
+        CachedItem {
+            item,
+            tokens: UnsafeCell::new(None),
+        }
+    }
+
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+
+    /// Mutable access to the item, which discards the cached tokens.
+    pub fn item_mut(&mut self) -> &mut Item {
+        *self.tokens.get_mut() = None;
+        &mut self.item
+    }
+
+    pub fn into_item(self) -> Item {
+        self.item
+    }
+
+    /// Whether the tokens have been printed since the item was last mutated.
+    pub fn is_cached(&self) -> bool {
+        unsafe { (*self.tokens.get()).is_some() }
+    }
+
+    /// The printed tokens of the item, printing them if they are not cached.
+    pub fn tokens(&self) -> &TokenStream {
+        let cache = self.tokens.get();
+        unsafe {
+            if let Some(tokens) = &*cache {
+                return tokens;
+            }
+            // The cache is empty, so no reference into it is alive.
+            let item = &self.item;
+            (*cache).get_or_insert_with(|| item.to_token_stream())
+        }
+    }
+}
+
+impl From<Item> for CachedItem {
+    fn from(item: Item) -> Self {
+        CachedItem::new(item)
+    }
+}
+
+impl ToTokens for CachedItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(self.tokens().clone());
+    }
+}
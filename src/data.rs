@@ -21,6 +21,23 @@ ast_struct! {
     }
 }
 
+impl Variant {
+    /// Whether the variant has named fields: `A { x: u8 }`.
+    pub fn is_named(&self) -> bool {
+        self.fields.is_named()
+    }
+
+    /// Whether the variant has unnamed fields: `B(u8)`.
+    pub fn is_tuple(&self) -> bool {
+        self.fields.is_tuple()
+    }
+
+    /// Whether the variant has no fields: `C`.
+    pub fn is_unit(&self) -> bool {
+        self.fields.is_unit()
+    }
+}
+
 ast_enum_of_structs! {
     /// Data stored within an enum variant or struct.
     ///
@@ -111,6 +128,31 @@ impl Fields {
             Fields::Unnamed(f) => f.unnamed.is_empty(),
         }
     }
+
+    /// Returns `true` for named fields: `{ x: u8 }`.
+    pub fn is_named(&self) -> bool {
+        match self {
+            Fields::Named(_) => true,
+            Fields::Unnamed(_) | Fields::Unit => false,
+        }
+    }
+
+    /// Returns `true` for unnamed fields: `(u8)`.
+    pub fn is_tuple(&self) -> bool {
+        match self {
+            Fields::Unnamed(_) => true,
+            Fields::Named(_) | Fields::Unit => false,
+        }
+    }
+
+    /// Returns `true` for the absence of fields, as in a unit struct or
+    /// variant.
+    pub fn is_unit(&self) -> bool {
+        match self {
+            Fields::Unit => true,
+            Fields::Named(_) | Fields::Unnamed(_) => false,
+        }
+    }
 }
 
 impl IntoIterator for Fields {
@@ -89,6 +89,7 @@ ast_struct! {
 pub mod parsing {
     use super::*;
 
+    use crate::buffer::{self, Cursor};
     use crate::parse::{Parse, ParseStream, Result};
 
     impl Parse for DeriveInput {
@@ -98,10 +99,11 @@ pub mod parsing {
 
             let lookahead = input.lookahead1();
             if lookahead.peek(Token![struct]) {
+                let begin = input.cursor();
                 let struct_token = input.parse::<Token![struct]>()?;
                 let ident = input.parse::<Ident>()?;
                 let generics = input.parse::<Generics>()?;
-                let (where_clause, fields, semi) = data_struct(input)?;
+                let (where_clause, fields, semi) = data_struct(input, begin)?;
                 Ok(DeriveInput {
                     attrs,
                     vis,
@@ -159,8 +161,11 @@ pub mod parsing {
         }
     }
 
+    // The `begin` cursor points at or before the `struct` keyword, so that a
+    // missing `;` can be reported at the last token of the struct.
     pub fn data_struct(
         input: ParseStream,
+        begin: Cursor,
     ) -> Result<(Option<WhereClause>, Fields, Option<Token![;]>)> {
         let mut lookahead = input.lookahead1();
         let mut where_clause = None;
@@ -182,7 +187,7 @@ pub mod parsing {
                 let semi = input.parse()?;
                 Ok((where_clause, Fields::Unnamed(fields), Some(semi)))
             } else {
-                Err(lookahead.error())
+                Err(missing_semi(input, begin, "tuple struct definition"))
             }
         } else if lookahead.peek(token::Brace) {
             let fields = input.parse()?;
@@ -191,10 +196,15 @@ pub mod parsing {
             let semi = input.parse()?;
             Ok((where_clause, Fields::Unit, Some(semi)))
         } else {
-            Err(lookahead.error())
+            Err(missing_semi(input, begin, "unit struct definition"))
         }
     }
 
+    pub fn missing_semi(input: ParseStream, begin: Cursor, what: &str) -> Error {
+        let span = buffer::last_span_before(begin, input.cursor());
+        Error::new(span, format!("expected `;` after {}", what))
+    }
+
     pub fn data_enum(
         input: ParseStream,
     ) -> Result<(
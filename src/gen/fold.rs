@@ -371,6 +371,10 @@ pub trait Fold {
         fold_item_enum(self, i)
     }
     #[cfg(feature = "full")]
+    fn fold_item_existential(&mut self, i: ItemExistential) -> ItemExistential {
+        fold_item_existential(self, i)
+    }
+    #[cfg(feature = "full")]
     fn fold_item_extern_crate(&mut self, i: ItemExternCrate) -> ItemExternCrate {
         fold_item_extern_crate(self, i)
     }
@@ -1916,6 +1920,7 @@ where
     match node {
         Item::Const(_binding_0) => Item::Const(f.fold_item_const(_binding_0)),
         Item::Enum(_binding_0) => Item::Enum(f.fold_item_enum(_binding_0)),
+        Item::Existential(_binding_0) => Item::Existential(f.fold_item_existential(_binding_0)),
         Item::ExternCrate(_binding_0) => Item::ExternCrate(f.fold_item_extern_crate(_binding_0)),
         Item::Fn(_binding_0) => Item::Fn(f.fold_item_fn(_binding_0)),
         Item::ForeignMod(_binding_0) => Item::ForeignMod(f.fold_item_foreign_mod(_binding_0)),
@@ -1967,6 +1972,23 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn fold_item_existential<F>(f: &mut F, node: ItemExistential) -> ItemExistential
+where
+    F: Fold + ?Sized,
+{
+    ItemExistential {
+        attrs: FoldHelper::lift(node.attrs, |it| f.fold_attribute(it)),
+        vis: f.fold_visibility(node.vis),
+        existential_token: crate::item::kw::existential(tokens_helper(f, &node.existential_token.span)),
+        type_token: Token![type](tokens_helper(f, &node.type_token.span)),
+        ident: f.fold_ident(node.ident),
+        generics: f.fold_generics(node.generics),
+        colon_token: Token ! [ : ](tokens_helper(f, &node.colon_token.spans)),
+        bounds: FoldHelper::lift(node.bounds, |it| f.fold_type_param_bound(it)),
+        semi_token: Token ! [ ; ](tokens_helper(f, &node.semi_token.spans)),
+    }
+}
+#[cfg(feature = "full")]
 pub fn fold_item_extern_crate<F>(f: &mut F, node: ItemExternCrate) -> ItemExternCrate
 where
     F: Fold + ?Sized,
@@ -2021,6 +2043,7 @@ where
         unsafety: (node.unsafety).map(|it| Token![unsafe](tokens_helper(f, &it.span))),
         impl_token: Token![impl](tokens_helper(f, &node.impl_token.span)),
         generics: f.fold_generics(node.generics),
+        constness: (node.constness).map(|it| Token![const](tokens_helper(f, &it.span))),
         trait_: (node.trait_).map(|it| {
             (
                 ((it).0).map(|it| Token![!](tokens_helper(f, &it.spans))),
@@ -2158,6 +2181,7 @@ where
         generics: f.fold_generics(node.generics),
         eq_token: Token ! [ = ](tokens_helper(f, &node.eq_token.spans)),
         ty: Box::new(f.fold_type(*node.ty)),
+        where_clause: (node.where_clause).map(|it| f.fold_where_clause(it)),
         semi_token: Token ! [ ; ](tokens_helper(f, &node.semi_token.spans)),
     }
 }
@@ -2448,6 +2472,7 @@ where
     F: Fold + ?Sized,
 {
     PartialBorrows {
+        mutability: (node.mutability).map(|it| Token![mut](tokens_helper(f, &it.span))),
         brace_token: Brace(tokens_helper(f, &node.brace_token.span)),
         borrows: FoldHelper::lift(node.borrows, |it| f.fold_partial_borrow(it)),
     }
@@ -2798,6 +2823,7 @@ where
     Signature {
         constness: (node.constness).map(|it| Token![const](tokens_helper(f, &it.span))),
         asyncness: (node.asyncness).map(|it| Token![async](tokens_helper(f, &it.span))),
+        genness: (node.genness).map(|it| crate::item::kw::gen(tokens_helper(f, &it.span))),
         unsafety: (node.unsafety).map(|it| Token![unsafe](tokens_helper(f, &it.span))),
         abi: (node.abi).map(|it| f.fold_abi(it)),
         fn_token: Token![fn](tokens_helper(f, &node.fn_token.span)),
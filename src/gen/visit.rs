@@ -372,6 +372,10 @@ pub trait Visit<'ast> {
         visit_item_enum(self, i)
     }
     #[cfg(feature = "full")]
+    fn visit_item_existential(&mut self, i: &'ast ItemExistential) {
+        visit_item_existential(self, i)
+    }
+    #[cfg(feature = "full")]
     fn visit_item_extern_crate(&mut self, i: &'ast ItemExternCrate) {
         visit_item_extern_crate(self, i)
     }
@@ -2186,6 +2190,9 @@ where
         Item::Enum(_binding_0) => {
             v.visit_item_enum(_binding_0);
         }
+        Item::Existential(_binding_0) => {
+            v.visit_item_existential(_binding_0);
+        }
         Item::ExternCrate(_binding_0) => {
             v.visit_item_extern_crate(_binding_0);
         }
@@ -2273,6 +2280,29 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn visit_item_existential<'ast, V>(v: &mut V, node: &'ast ItemExistential)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in &node.attrs {
+        v.visit_attribute(it)
+    }
+    v.visit_visibility(&node.vis);
+    tokens_helper(v, &node.existential_token.span);
+    tokens_helper(v, &node.type_token.span);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    tokens_helper(v, &node.colon_token.spans);
+    for el in Punctuated::pairs(&node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound(it);
+        if let Some(p) = p {
+            tokens_helper(v, &p.spans);
+        }
+    }
+    tokens_helper(v, &node.semi_token.spans);
+}
+#[cfg(feature = "full")]
 pub fn visit_item_extern_crate<'ast, V>(v: &mut V, node: &'ast ItemExternCrate)
 where
     V: Visit<'ast> + ?Sized,
@@ -2332,6 +2362,9 @@ where
     };
     tokens_helper(v, &node.impl_token.span);
     v.visit_generics(&node.generics);
+    if let Some(it) = &node.constness {
+        tokens_helper(v, &it.span)
+    };
     if let Some(it) = &node.trait_ {
         if let Some(it) = &(it).0 {
             tokens_helper(v, &it.spans)
@@ -2501,6 +2534,9 @@ where
     v.visit_generics(&node.generics);
     tokens_helper(v, &node.eq_token.spans);
     v.visit_type(&*node.ty);
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it)
+    };
     tokens_helper(v, &node.semi_token.spans);
 }
 #[cfg(feature = "full")]
@@ -2804,6 +2840,9 @@ pub fn visit_partial_borrows<'ast, V>(v: &mut V, node: &'ast PartialBorrows)
 where
     V: Visit<'ast> + ?Sized,
 {
+    if let Some(it) = &node.mutability {
+        tokens_helper(v, &it.span)
+    };
     tokens_helper(v, &node.brace_token.span);
     for el in Punctuated::pairs(&node.borrows) {
         let (it, p) = el.into_tuple();
@@ -3246,6 +3285,9 @@ where
     if let Some(it) = &node.asyncness {
         tokens_helper(v, &it.span)
     };
+    if let Some(it) = &node.genness {
+        tokens_helper(v, &it.span)
+    };
     if let Some(it) = &node.unsafety {
         tokens_helper(v, &it.span)
     };
@@ -376,6 +376,10 @@ pub trait VisitMut {
         visit_item_enum_mut(self, i)
     }
     #[cfg(feature = "full")]
+    fn visit_item_existential_mut(&mut self, i: &mut ItemExistential) {
+        visit_item_existential_mut(self, i)
+    }
+    #[cfg(feature = "full")]
     fn visit_item_extern_crate_mut(&mut self, i: &mut ItemExternCrate) {
         visit_item_extern_crate_mut(self, i)
     }
@@ -2192,6 +2196,9 @@ where
         Item::Enum(_binding_0) => {
             v.visit_item_enum_mut(_binding_0);
         }
+        Item::Existential(_binding_0) => {
+            v.visit_item_existential_mut(_binding_0);
+        }
         Item::ExternCrate(_binding_0) => {
             v.visit_item_extern_crate_mut(_binding_0);
         }
@@ -2279,6 +2286,29 @@ where
     }
 }
 #[cfg(feature = "full")]
+pub fn visit_item_existential_mut<V>(v: &mut V, node: &mut ItemExistential)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in &mut node.attrs {
+        v.visit_attribute_mut(it)
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    tokens_helper(v, &mut node.existential_token.span);
+    tokens_helper(v, &mut node.type_token.span);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    tokens_helper(v, &mut node.colon_token.spans);
+    for el in Punctuated::pairs_mut(&mut node.bounds) {
+        let (it, p) = el.into_tuple();
+        v.visit_type_param_bound_mut(it);
+        if let Some(p) = p {
+            tokens_helper(v, &mut p.spans);
+        }
+    }
+    tokens_helper(v, &mut node.semi_token.spans);
+}
+#[cfg(feature = "full")]
 pub fn visit_item_extern_crate_mut<V>(v: &mut V, node: &mut ItemExternCrate)
 where
     V: VisitMut + ?Sized,
@@ -2338,6 +2368,9 @@ where
     };
     tokens_helper(v, &mut node.impl_token.span);
     v.visit_generics_mut(&mut node.generics);
+    if let Some(it) = &mut node.constness {
+        tokens_helper(v, &mut it.span)
+    };
     if let Some(it) = &mut node.trait_ {
         if let Some(it) = &mut (it).0 {
             tokens_helper(v, &mut it.spans)
@@ -2507,6 +2540,9 @@ where
     v.visit_generics_mut(&mut node.generics);
     tokens_helper(v, &mut node.eq_token.spans);
     v.visit_type_mut(&mut *node.ty);
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it)
+    };
     tokens_helper(v, &mut node.semi_token.spans);
 }
 #[cfg(feature = "full")]
@@ -2810,6 +2846,9 @@ pub fn visit_partial_borrows_mut<V>(v: &mut V, node: &mut PartialBorrows)
 where
     V: VisitMut + ?Sized,
 {
+    if let Some(it) = &mut node.mutability {
+        tokens_helper(v, &mut it.span)
+    };
     tokens_helper(v, &mut node.brace_token.span);
     for el in Punctuated::pairs_mut(&mut node.borrows) {
         let (it, p) = el.into_tuple();
@@ -3252,6 +3291,9 @@ where
     if let Some(it) = &mut node.asyncness {
         tokens_helper(v, &mut it.span)
     };
+    if let Some(it) = &mut node.genness {
+        tokens_helper(v, &mut it.span)
+    };
     if let Some(it) = &mut node.unsafety {
         tokens_helper(v, &mut it.span)
     };
@@ -778,7 +778,7 @@ pub mod parsing {
                 eq_token: {
                     if input.peek(Token![=]) {
                         let eq_token = input.parse()?;
-                        default = Some(input.parse::<Expr>()?);
+                        default = Some(const_param_default(input)?);
                         Some(eq_token)
                     } else {
                         None
@@ -789,6 +789,37 @@ pub mod parsing {
         }
     }
 
+    // The default of a const parameter is followed by `,` or `>`, so only a
+    // literal, a negated literal, a block or a path may appear without
+    // parentheses. Parsing an arbitrary expression would consume `4 > ...` as
+    // a comparison.
+    fn const_param_default(input: ParseStream) -> Result<Expr> {
+        if input.peek(Lit) {
+            return input.parse().map(Expr::Lit);
+        }
+
+        if input.peek(Token![-]) && input.peek2(Lit) {
+            return Ok(Expr::Unary(ExprUnary {
+                attrs: Vec::new(),
+                op: UnOp::Neg(input.parse()?),
+                expr: Box::new(Expr::Lit(input.parse()?)),
+            }));
+        }
+
+        #[cfg(feature = "full")]
+        {
+            if input.peek(token::Brace) {
+                return input.call(expr::parsing::expr_block).map(Expr::Block);
+            }
+        }
+
+        Ok(Expr::Path(ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: input.parse()?,
+        }))
+    }
+
     impl Parse for WhereClause {
         fn parse(input: ParseStream) -> Result<Self> {
             Ok(WhereClause {
@@ -1,14 +1,25 @@
 use super::*;
+use crate::attr::FilterAttrs;
 use crate::derive::{Data, DataEnum, DataStruct, DataUnion, DeriveInput};
-use crate::punctuated::Punctuated;
+use crate::punctuated::{Pair, Punctuated};
 use crate::partial_borrows::PartialBorrows;
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use std::slice;
 
 #[cfg(feature = "extra-traits")]
 use crate::tt::TokenStreamHelper;
 #[cfg(feature = "extra-traits")]
 use std::hash::{Hash, Hasher};
 
+// Neither `gen` nor `existential` is a Rust keyword, so they are kept out of
+// the `Token!` macro and parsed as custom keywords instead. Their constructor
+// functions go unused when Syn is built without parsing or fold.
+#[allow(dead_code)]
+pub(crate) mod kw {
+    crate::custom_keyword!(gen);
+    crate::custom_keyword!(existential);
+}
+
 ast_enum_of_structs! {
     /// Things that can appear directly inside of a module or scope.
     ///
@@ -29,6 +40,9 @@ ast_enum_of_structs! {
         /// An enum definition: `enum Foo<A, B> { A(A), B(B) }`.
         Enum(ItemEnum),
 
+        /// An existential type: `existential type Iter: Iterator<Item = u8>`.
+        Existential(ItemExistential),
+
         /// An `extern crate` item: `extern crate serde`.
         ExternCrate(ItemExternCrate),
 
@@ -113,6 +127,66 @@ ast_struct! {
     }
 }
 
+impl ItemEnum {
+    /// Whether the enum is annotated `#[non_exhaustive]`.
+    pub fn is_non_exhaustive(&self) -> bool {
+        has_attr(&self.attrs, "non_exhaustive")
+    }
+
+    /// Whether the enum has a `where` clause.
+    pub fn has_where_clause(&self) -> bool {
+        self.generics.where_clause.is_some()
+    }
+
+    /// Looks up the variant with the given name.
+    pub fn variant(&self, name: &str) -> Option<&Variant> {
+        self.variants.iter().find(|variant| variant.ident == name)
+    }
+
+    /// Returns an iterator over the names of the variants, in declaration
+    /// order.
+    pub fn variant_idents(&self) -> impl Iterator<Item = &Ident> {
+        self.variants.iter().map(|variant| &variant.ident)
+    }
+
+    /// The type of every field of every variant, in declaration order.
+    pub fn field_types(&self) -> impl Iterator<Item = &Type> {
+        self.variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .map(|field| &field.ty)
+    }
+
+    /// Pairs the name of each variant with its discriminant expression, like
+    /// the `10` in `C = 10`, or `None` if it has no explicit discriminant.
+    pub fn explicit_discriminants(&self) -> Vec<(&Ident, Option<&Expr>)> {
+        self.variants
+            .iter()
+            .map(|variant| {
+                let discriminant = variant.discriminant.as_ref().map(|(_, expr)| expr);
+                (&variant.ident, discriminant)
+            })
+            .collect()
+    }
+}
+
+ast_struct! {
+    /// An existential type: `existential type Iter: Iterator<Item = u8>`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct ItemExistential {
+        pub attrs: Vec<Attribute>,
+        pub vis: Visibility,
+        pub existential_token: kw::existential,
+        pub type_token: Token![type],
+        pub ident: Ident,
+        pub generics: Generics,
+        pub colon_token: Token![:],
+        pub bounds: Punctuated<TypeParamBound, Token![+]>,
+        pub semi_token: Token![;],
+    }
+}
+
 ast_struct! {
     /// An `extern crate` item: `extern crate serde`.
     ///
@@ -128,6 +202,13 @@ ast_struct! {
     }
 }
 
+impl ItemExternCrate {
+    /// Whether this refers to the current crate: `extern crate self as name;`.
+    pub fn is_self(&self) -> bool {
+        self.ident == "self"
+    }
+}
+
 ast_struct! {
     /// A free-standing function: `fn process(n: usize) -> Result<()> { ...
     /// }`.
@@ -141,6 +222,68 @@ ast_struct! {
     }
 }
 
+impl ItemFn {
+    /// Whether the function is annotated `#[track_caller]`.
+    pub fn is_track_caller(&self) -> bool {
+        has_attr(&self.attrs, "track_caller")
+    }
+
+    /// Whether the function has a `where` clause.
+    pub fn has_where_clause(&self) -> bool {
+        self.sig.generics.where_clause.is_some()
+    }
+
+    /// Whether this is a `fn main` that could be the crate's entry point.
+    ///
+    /// Only the name and the absence of a receiver are checked, so `fn
+    /// main(x: u8)` counts even though the compiler would reject it.
+    pub fn is_entrypoint(&self) -> bool {
+        self.sig.ident == "main" && !self.sig.has_receiver()
+    }
+
+    /// Whether the function is annotated `#[start]`, which replaces `fn main`
+    /// as the entry point of the binary.
+    pub fn has_start_attr(&self) -> bool {
+        has_attr(&self.attrs, "start")
+    }
+
+    /// The statements of the function body.
+    pub fn stmts(&self) -> &[Stmt] {
+        &self.block.stmts
+    }
+
+    /// Mutable access to the statements of the function body.
+    pub fn stmts_mut(&mut self) -> &mut Vec<Stmt> {
+        &mut self.block.stmts
+    }
+
+    /// The symbol name the function is exported under: the value of
+    /// `#[export_name = "..."]` if present, otherwise the function's own name
+    /// if it is `#[no_mangle]`, otherwise `None`.
+    ///
+    /// *This method is available if Syn is built with the `"parsing"`
+    /// feature.*
+    #[cfg(feature = "parsing")]
+    pub fn export_name(&self) -> Option<String> {
+        for attr in &self.attrs {
+            if !attr.path.is_ident("export_name") {
+                continue;
+            }
+            if let Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) = attr.parse_meta()
+            {
+                return Some(lit.value());
+            }
+        }
+        if has_attr(&self.attrs, "no_mangle") {
+            Some(self.sig.ident.to_string())
+        } else {
+            None
+        }
+    }
+}
+
 ast_struct! {
     /// A block of foreign items: `extern "C" { ... }`.
     ///
@@ -163,7 +306,14 @@ ast_struct! {
         pub defaultness: Option<Token![default]>,
         pub unsafety: Option<Token![unsafe]>,
         pub impl_token: Token![impl],
+        /// Generic parameters of the impl.
+        ///
+        /// An empty parameter list `impl<>` parses to generics with no
+        /// params, keeping the angle bracket tokens, and is printed as if the
+        /// brackets were absent: `impl<> Foo {}` prints as `impl Foo {}`.
         pub generics: Generics,
+        /// The `const` in `impl const Trait for T`.
+        pub constness: Option<Token![const]>,
         /// Trait this impl implements.
         pub trait_: Option<(Option<Token![!]>, Path, Token![for])>,
         /// The Self type of the impl.
@@ -173,6 +323,66 @@ ast_struct! {
     }
 }
 
+impl ItemImpl {
+    /// The attributes written outside of the impl block, like
+    /// `#[automatically_derived]`.
+    pub fn outer_attrs(&self) -> impl Iterator<Item = &Attribute> {
+        self.attrs.outer()
+    }
+
+    /// The attributes written inside of the impl block, like
+    /// `#![allow(dead_code)]`.
+    ///
+    /// These are kept in `attrs` after the outer attributes, each with an
+    /// [`AttrStyle::Inner`] style.
+    pub fn inner_attrs(&self) -> impl Iterator<Item = &Attribute> {
+        self.attrs.inner()
+    }
+
+    /// The associated consts of the impl, in declaration order.
+    pub fn associated_consts(&self) -> impl Iterator<Item = &ImplItemConst> {
+        self.items.iter().filter_map(|item| match item {
+            ImplItem::Const(item) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// The methods of the impl, in declaration order.
+    pub fn methods(&self) -> impl Iterator<Item = &ImplItemMethod> {
+        self.items.iter().filter_map(|item| match item {
+            ImplItem::Method(item) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// The associated types of the impl, in declaration order.
+    pub fn associated_types(&self) -> impl Iterator<Item = &ImplItemType> {
+        self.items.iter().filter_map(|item| match item {
+            ImplItem::Type(item) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// The name of the type being implemented, like `Bar` in `impl<T> Bar<T>`
+    /// or `impl Trait for a::Bar`.
+    ///
+    /// Returns `None` if `self_ty` is anything other than a plain path, such
+    /// as a tuple, a reference or `<T as Trait>::Assoc`.
+    pub fn self_ty_ident(&self) -> Option<&Ident> {
+        match &*self.self_ty {
+            Type::Path(TypePath { qself: None, path }) => {
+                path.segments.last().map(|segment| &segment.ident)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the impl has a `where` clause.
+    pub fn has_where_clause(&self) -> bool {
+        self.generics.where_clause.is_some()
+    }
+}
+
 ast_struct! {
     /// A macro invocation, which includes `macro_rules!` definitions.
     ///
@@ -182,6 +392,8 @@ ast_struct! {
         /// The `example` in `macro_rules! example { ... }`.
         pub ident: Option<Ident>,
         pub mac: Macro,
+        /// Required after a parenthesized or bracketed macro, optional after
+        /// a braced one: `m! { ... };`.
         pub semi_token: Option<Token![;]>,
     }
 }
@@ -199,6 +411,94 @@ ast_struct! {
     }
 }
 
+impl ItemMacro2 {
+    /// The parameter list of a single-rule macro: the `($x:expr)` in `macro
+    /// m($x:expr) { $x }`.
+    ///
+    /// Returns `None` for a macro defined by a list of rules, like `macro m {
+    /// () => {} }`, and if `rules` does not have the shape produced by the
+    /// parser.
+    pub fn params(&self) -> Option<Group> {
+        self.split_rules().0
+    }
+
+    /// The braced body of the macro: either the transcriber of a single-rule
+    /// macro or the list of rules.
+    ///
+    /// Returns `None` if `rules` does not have the shape produced by the
+    /// parser.
+    pub fn body(&self) -> Option<Group> {
+        self.split_rules().1
+    }
+
+    /// The tokens following the macro name, exactly as parsed.
+    pub fn raw(&self) -> &TokenStream {
+        &self.rules
+    }
+
+    /// The metavariables declared by the parameter list of a single-rule
+    /// macro, each as its name and fragment specifier: `(x, expr)` and `(y,
+    /// ty)` for `macro m($x:expr, $($y:ty),*) { ... }`.
+    ///
+    /// Metavariables inside repetitions are included, in the order they are
+    /// written. Returns an empty list if [`params`] is `None`.
+    ///
+    /// [`params`]: ItemMacro2::params
+    pub fn param_fragments(&self) -> Vec<(Ident, Ident)> {
+        let mut fragments = Vec::new();
+        if let Some(params) = self.params() {
+            collect_fragments(params.stream(), &mut fragments);
+        }
+        fragments
+    }
+
+    fn split_rules(&self) -> (Option<Group>, Option<Group>) {
+        let mut params = None;
+        let mut tokens = self.rules.clone().into_iter().peekable();
+        if let Some(TokenTree::Group(group)) = tokens.peek() {
+            if group.delimiter() == Delimiter::Parenthesis {
+                params = Some(group.clone());
+                tokens.next();
+            }
+        }
+        match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Group(body)), None) if body.delimiter() == Delimiter::Brace => {
+                (params, Some(body))
+            }
+            _ => (None, None),
+        }
+    }
+}
+
+fn collect_fragments(tokens: TokenStream, fragments: &mut Vec<(Ident, Ident)>) {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let TokenTree::Group(group) = &tokens[i] {
+            collect_fragments(group.stream(), fragments);
+        }
+        let fragment = match (
+            &tokens[i],
+            tokens.get(i + 1),
+            tokens.get(i + 2),
+            tokens.get(i + 3),
+        ) {
+            (
+                TokenTree::Punct(dollar),
+                Some(TokenTree::Ident(name)),
+                Some(TokenTree::Punct(colon)),
+                Some(TokenTree::Ident(kind)),
+            ) if dollar.as_char() == '$' && colon.as_char() == ':' => (name.clone(), kind.clone()),
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        fragments.push(fragment);
+        i += 4;
+    }
+}
+
 ast_struct! {
     /// A module or module declaration: `mod m` or `mod m { ... }`.
     ///
@@ -213,6 +513,92 @@ ast_struct! {
     }
 }
 
+impl ItemMod {
+    /// The names re-exported by `pub use` items in an inline module, each
+    /// paired with the path it is imported from.
+    ///
+    /// Grouped imports produce one edge per imported name, so `pub use a::b
+    /// as c;` yields `(c, [a, b])` and `pub use a::{b, c::d};` yields `(b, [a,
+    /// b])` and `(d, [a, c, d])`. Glob imports and `as _` imports bring no
+    /// nameable item into scope and are skipped, as are `use` items that are
+    /// not `pub`.
+    pub fn reexport_edges(&self) -> Vec<(Ident, Vec<Ident>)> {
+        let items = match &self.content {
+            Some((_, items)) => items,
+            None => return Vec::new(),
+        };
+
+        let mut edges = Vec::new();
+        for item in items {
+            let item = match item {
+                Item::Use(item @ ItemUse { vis: Visibility::Public(_), .. }) => item,
+                _ => continue,
+            };
+            for_each_use_leaf(&item.tree, &mut Vec::new(), &mut |path, leaf| {
+                let name = match use_leaf_binding(path, leaf) {
+                    Some(name) => name.clone(),
+                    None => return,
+                };
+                let mut source: Vec<Ident> = path.iter().map(|&ident| ident.clone()).collect();
+                match leaf {
+                    UseTree::Name(UseName { ident, .. })
+                    | UseTree::Rename(UseRename { ident, .. })
+                        if ident != "self" =>
+                    {
+                        source.push(ident.clone());
+                    }
+                    _ => {}
+                }
+                edges.push((name, source));
+            });
+        }
+        edges
+    }
+
+    /// Iterates depth-first over every item in the module and in its inline
+    /// submodules, yielding each submodule before its contents.
+    ///
+    /// Module declarations without content, like `mod m;`, are yielded but
+    /// not descended into.
+    pub fn items_recursive(&self) -> impl Iterator<Item = &Item> {
+        let items: &[Item] = match &self.content {
+            Some((_, items)) => items,
+            None => &[],
+        };
+        ItemsRecursive {
+            stack: vec![items.iter()],
+        }
+    }
+}
+
+struct ItemsRecursive<'a> {
+    stack: Vec<slice::Iter<'a, Item>>,
+}
+
+impl<'a> Iterator for ItemsRecursive<'a> {
+    type Item = &'a Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = match self.stack.last_mut()?.next() {
+                Some(item) => item,
+                None => {
+                    self.stack.pop();
+                    continue;
+                }
+            };
+            if let Item::Mod(ItemMod {
+                content: Some((_, items)),
+                ..
+            }) = item
+            {
+                self.stack.push(items.iter());
+            }
+            return Some(item);
+        }
+    }
+}
+
 ast_struct! {
     /// A static item: `static BIKE: Shed = Shed(42)`.
     ///
@@ -246,6 +632,53 @@ ast_struct! {
     }
 }
 
+impl ItemStruct {
+    /// Whether the struct is annotated `#[non_exhaustive]`.
+    pub fn is_non_exhaustive(&self) -> bool {
+        has_attr(&self.attrs, "non_exhaustive")
+    }
+
+    /// Whether the struct has a `where` clause.
+    pub fn has_where_clause(&self) -> bool {
+        self.generics.where_clause.is_some()
+    }
+
+    /// Whether the struct has named fields: `struct A { x: u8 }`.
+    pub fn is_named(&self) -> bool {
+        self.fields.is_named()
+    }
+
+    /// Whether the struct is a tuple struct: `struct B(u8);`.
+    pub fn is_tuple(&self) -> bool {
+        self.fields.is_tuple()
+    }
+
+    /// Whether the struct is a unit struct: `struct C;`.
+    pub fn is_unit(&self) -> bool {
+        self.fields.is_unit()
+    }
+
+    /// The fields whose type is `PhantomData<...>`, recognized by the last
+    /// segment of the type's path.
+    pub fn phantom_fields(&self) -> Vec<&Field> {
+        self.fields
+            .iter()
+            .filter(|field| match &field.ty {
+                Type::Path(ty) => match ty.path.segments.last() {
+                    Some(segment) => segment.ident == "PhantomData",
+                    None => false,
+                },
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// The type of each field, in declaration order.
+    pub fn field_types(&self) -> impl Iterator<Item = &Type> {
+        self.fields.iter().map(|field| &field.ty)
+    }
+}
+
 ast_struct! {
     /// A trait definition: `pub trait Iterator { ... }`.
     ///
@@ -265,6 +698,120 @@ ast_struct! {
     }
 }
 
+impl ItemTrait {
+    /// Whether this is an `auto trait`.
+    pub fn is_auto(&self) -> bool {
+        self.auto_token.is_some()
+    }
+
+    /// Whether this is an `unsafe trait`.
+    pub fn is_unsafe(&self) -> bool {
+        self.unsafety.is_some()
+    }
+
+    /// Whether the trait has a `where` clause.
+    pub fn has_where_clause(&self) -> bool {
+        self.generics.where_clause.is_some()
+    }
+
+    /// Whether the trait has neither items nor supertraits, like `trait
+    /// Marker {}`.
+    pub fn is_marker_like(&self) -> bool {
+        self.items.is_empty() && self.supertraits.is_empty()
+    }
+
+    /// The path of each supertrait, in declaration order, skipping lifetime
+    /// bounds: `B` and `C<u8>` in `trait A: B + 'static + C<u8>`.
+    pub fn supertrait_paths(&self) -> impl Iterator<Item = &Path> {
+        self.supertraits.iter().filter_map(|bound| match bound {
+            TypeParamBound::Trait(bound) => Some(&bound.path),
+            TypeParamBound::Lifetime(_) => None,
+        })
+    }
+
+    /// The associated consts of the trait, in declaration order.
+    pub fn associated_consts(&self) -> impl Iterator<Item = &TraitItemConst> {
+        self.items.iter().filter_map(|item| match item {
+            TraitItem::Const(item) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// The methods of the trait, in declaration order.
+    pub fn methods(&self) -> impl Iterator<Item = &TraitItemMethod> {
+        self.items.iter().filter_map(|item| match item {
+            TraitItem::Method(item) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// The methods without a default body, which every impl must provide.
+    pub fn required_methods(&self) -> impl Iterator<Item = &TraitItemMethod> {
+        self.methods().filter(|method| method.default.is_none())
+    }
+
+    /// The methods with a default body.
+    pub fn provided_methods(&self) -> impl Iterator<Item = &TraitItemMethod> {
+        self.methods().filter(|method| method.default.is_some())
+    }
+
+    /// The associated types of the trait, in declaration order.
+    pub fn associated_types(&self) -> impl Iterator<Item = &TraitItemType> {
+        self.items.iter().filter_map(|item| match item {
+            TraitItem::Type(item) => Some(item),
+            _ => None,
+        })
+    }
+
+    /// The name and declared type of each associated const in the trait, in
+    /// declaration order.
+    pub fn assoc_const_types(&self) -> Vec<(&Ident, &Type)> {
+        self.associated_consts()
+            .map(|item| (&item.ident, &item.ty))
+            .collect()
+    }
+
+    /// Whether the trait appears to be sealed, following the convention of
+    /// requiring a supertrait named `Sealed` from a private module.
+    ///
+    /// This is a heuristic: it looks for a supertrait whose path ends in
+    /// `Sealed`, either in the supertrait list as in `trait T:
+    /// private::Sealed` or in the where clause as in `trait T where Self:
+    /// private::Sealed`. It does not check that the `Sealed` trait is in fact
+    /// unnameable outside of the crate.
+    pub fn is_sealed(&self) -> bool {
+        fn any_sealed(bounds: &Punctuated<TypeParamBound, Token![+]>) -> bool {
+            bounds.iter().any(|bound| match bound {
+                TypeParamBound::Trait(bound) => match bound.path.segments.last() {
+                    Some(segment) => segment.ident == "Sealed",
+                    None => false,
+                },
+                TypeParamBound::Lifetime(_) => false,
+            })
+        }
+
+        fn is_self(ty: &Type) -> bool {
+            match ty {
+                Type::Path(ty) => ty.qself.is_none() && path_is_ident(&ty.path, "Self"),
+                _ => false,
+            }
+        }
+
+        if any_sealed(&self.supertraits) {
+            return true;
+        }
+        match &self.generics.where_clause {
+            Some(where_clause) => where_clause.predicates.iter().any(|predicate| match predicate {
+                WherePredicate::Type(predicate) => {
+                    is_self(&predicate.bounded_ty) && any_sealed(&predicate.bounds)
+                }
+                _ => false,
+            }),
+            None => false,
+        }
+    }
+}
+
 ast_struct! {
     /// A trait alias: `pub trait SharableIterator = Iterator + Sync`.
     ///
@@ -284,6 +831,10 @@ ast_struct! {
 ast_struct! {
     /// A type alias: `type Result<T> = std::result::Result<T, MyError>`.
     ///
+    /// A where clause before the `=` is stored in `generics`, while one
+    /// trailing the aliased type, as in `type Foo<T> = Bar<T> where T: Send;`,
+    /// is stored in `where_clause`.
+    ///
     /// *This type is available if Syn is built with the `"full"` feature.*
     pub struct ItemType {
         pub attrs: Vec<Attribute>,
@@ -293,10 +844,19 @@ ast_struct! {
         pub generics: Generics,
         pub eq_token: Token![=],
         pub ty: Box<Type>,
+        pub where_clause: Option<WhereClause>,
         pub semi_token: Token![;],
     }
 }
 
+impl ItemType {
+    /// Whether the type alias has a `where` clause, either before the `=` or
+    /// trailing the aliased type.
+    pub fn has_where_clause(&self) -> bool {
+        self.generics.where_clause.is_some() || self.where_clause.is_some()
+    }
+}
+
 ast_struct! {
     /// A union definition: `union Foo<A, B> { x: A, y: B }`.
     ///
@@ -311,6 +871,29 @@ ast_struct! {
     }
 }
 
+impl ItemUnion {
+    /// The name of each field, in declaration order.
+    ///
+    /// Note that reading a union field requires `unsafe` in Rust, since the
+    /// compiler does not track which field was last written.
+    pub fn field_idents(&self) -> impl Iterator<Item = &Ident> {
+        self.fields.named.iter().filter_map(|field| field.ident.as_ref())
+    }
+
+    /// The field with the given name.
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.named.iter().find(|field| match &field.ident {
+            Some(ident) => ident == name,
+            None => false,
+        })
+    }
+
+    /// The type of each field, in declaration order.
+    pub fn field_types(&self) -> impl Iterator<Item = &Type> {
+        self.fields.named.iter().map(|field| &field.ty)
+    }
+}
+
 ast_struct! {
     /// A use declaration: `use std::collections::HashMap`.
     ///
@@ -325,6 +908,31 @@ ast_struct! {
     }
 }
 
+impl ItemUse {
+    /// Whether any part of the import is a glob: `use a::{b, c::*};`.
+    pub fn is_glob(&self) -> bool {
+        let mut is_glob = false;
+        for_each_use_leaf(&self.tree, &mut Vec::new(), &mut |_, leaf| {
+            if let UseTree::Glob(_) = leaf {
+                is_glob = true;
+            }
+        });
+        is_glob
+    }
+
+    /// The names this import brings into scope, in source order.
+    ///
+    /// Renamed imports contribute the new name, `a::{self}` contributes `a`,
+    /// and globs and `as _` imports contribute nothing.
+    pub fn bound_idents(&self) -> Vec<&Ident> {
+        let mut idents = Vec::new();
+        for_each_use_leaf(&self.tree, &mut Vec::new(), &mut |path, leaf| {
+            idents.extend(use_leaf_binding(path, leaf));
+        });
+        idents
+    }
+}
+
 #[cfg(feature = "extra-traits")]
 impl Eq for Item {}
 
@@ -334,6 +942,7 @@ impl PartialEq for Item {
         match (self, other) {
             (Item::Const(this), Item::Const(other)) => this == other,
             (Item::Enum(this), Item::Enum(other)) => this == other,
+            (Item::Existential(this), Item::Existential(other)) => this == other,
             (Item::ExternCrate(this), Item::ExternCrate(other)) => this == other,
             (Item::Fn(this), Item::Fn(other)) => this == other,
             (Item::ForeignMod(this), Item::ForeignMod(other)) => this == other,
@@ -371,64 +980,68 @@ impl Hash for Item {
                 state.write_u8(1);
                 item.hash(state);
             }
-            Item::ExternCrate(item) => {
+            Item::Existential(item) => {
                 state.write_u8(2);
                 item.hash(state);
             }
-            Item::Fn(item) => {
+            Item::ExternCrate(item) => {
                 state.write_u8(3);
                 item.hash(state);
             }
-            Item::ForeignMod(item) => {
+            Item::Fn(item) => {
                 state.write_u8(4);
                 item.hash(state);
             }
-            Item::Impl(item) => {
+            Item::ForeignMod(item) => {
                 state.write_u8(5);
                 item.hash(state);
             }
-            Item::Macro(item) => {
+            Item::Impl(item) => {
                 state.write_u8(6);
                 item.hash(state);
             }
-            Item::Macro2(item) => {
+            Item::Macro(item) => {
                 state.write_u8(7);
                 item.hash(state);
             }
-            Item::Mod(item) => {
+            Item::Macro2(item) => {
                 state.write_u8(8);
                 item.hash(state);
             }
-            Item::Static(item) => {
+            Item::Mod(item) => {
                 state.write_u8(9);
                 item.hash(state);
             }
-            Item::Struct(item) => {
+            Item::Static(item) => {
                 state.write_u8(10);
                 item.hash(state);
             }
-            Item::Trait(item) => {
+            Item::Struct(item) => {
                 state.write_u8(11);
                 item.hash(state);
             }
-            Item::TraitAlias(item) => {
+            Item::Trait(item) => {
                 state.write_u8(12);
                 item.hash(state);
             }
-            Item::Type(item) => {
+            Item::TraitAlias(item) => {
                 state.write_u8(13);
                 item.hash(state);
             }
-            Item::Union(item) => {
+            Item::Type(item) => {
                 state.write_u8(14);
                 item.hash(state);
             }
-            Item::Use(item) => {
+            Item::Union(item) => {
                 state.write_u8(15);
                 item.hash(state);
             }
-            Item::Verbatim(item) => {
+            Item::Use(item) => {
                 state.write_u8(16);
+                item.hash(state);
+            }
+            Item::Verbatim(item) => {
+                state.write_u8(17);
                 TokenStreamHelper(item).hash(state);
             }
             Item::__Nonexhaustive => unreachable!(),
@@ -436,6 +1049,108 @@ impl Hash for Item {
     }
 }
 
+#[cfg(all(feature = "extra-traits", feature = "clone-impls", feature = "visit-mut"))]
+impl Item {
+    /// Compares two items for equality, treating every punctuated list within
+    /// them as equal regardless of whether it has trailing punctuation.
+    ///
+    /// This means `enum E { A, B }` and `enum E { A, B, }` compare equal. The
+    /// comma of a one-element tuple is not trailing punctuation, so the types
+    /// `(u8,)` and `(u8)` still compare unequal.
+    ///
+    /// *This method is available if Syn is built with the `"full"`,
+    /// `"extra-traits"`, `"clone-impls"` and `"visit-mut"` features.*
+    pub fn eq_ignoring_trailing_punct(&self, other: &Self) -> bool {
+        use crate::visit_mut::VisitMut;
+
+        let mut this = self.clone();
+        let mut other = other.clone();
+        StripTrailingPunct.visit_item_mut(&mut this);
+        StripTrailingPunct.visit_item_mut(&mut other);
+        this == other
+    }
+}
+
+#[cfg(all(feature = "extra-traits", feature = "clone-impls", feature = "visit-mut"))]
+struct StripTrailingPunct;
+
+#[cfg(all(feature = "extra-traits", feature = "clone-impls", feature = "visit-mut"))]
+impl StripTrailingPunct {
+    fn strip<T, P>(punctuated: &mut Punctuated<T, P>) {
+        if punctuated.trailing_punct() {
+            if let Some(pair) = punctuated.pop() {
+                punctuated.push_value(pair.into_value());
+            }
+        }
+    }
+
+    // A one-element tuple needs its comma to stay a tuple.
+    fn strip_tuple<T, P>(elems: &mut Punctuated<T, P>) {
+        if elems.len() > 1 {
+            StripTrailingPunct::strip(elems);
+        }
+    }
+}
+
+#[cfg(all(feature = "extra-traits", feature = "clone-impls", feature = "visit-mut"))]
+macro_rules! strip_trailing_punct {
+    ($($visit:ident($ty:ident.$field:ident, $strip:ident);)*) => {
+        $(
+            fn $visit(&mut self, node: &mut $ty) {
+                crate::visit_mut::$visit(self, node);
+                StripTrailingPunct::$strip(&mut node.$field);
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "extra-traits", feature = "clone-impls", feature = "visit-mut"))]
+impl crate::visit_mut::VisitMut for StripTrailingPunct {
+    strip_trailing_punct! {
+        visit_angle_bracketed_generic_arguments_mut(AngleBracketedGenericArguments.args, strip);
+        visit_bound_lifetimes_mut(BoundLifetimes.lifetimes, strip);
+        visit_constraint_mut(Constraint.bounds, strip);
+        visit_expr_array_mut(ExprArray.elems, strip);
+        visit_expr_call_mut(ExprCall.args, strip);
+        visit_expr_closure_mut(ExprClosure.inputs, strip);
+        visit_expr_method_call_mut(ExprMethodCall.args, strip);
+        visit_expr_struct_mut(ExprStruct.fields, strip);
+        visit_expr_tuple_mut(ExprTuple.elems, strip_tuple);
+        visit_fields_named_mut(FieldsNamed.named, strip);
+        visit_fields_unnamed_mut(FieldsUnnamed.unnamed, strip);
+        visit_generics_mut(Generics.params, strip);
+        visit_item_enum_mut(ItemEnum.variants, strip);
+        visit_item_existential_mut(ItemExistential.bounds, strip);
+        visit_item_trait_mut(ItemTrait.supertraits, strip);
+        visit_item_trait_alias_mut(ItemTraitAlias.bounds, strip);
+        visit_lifetime_def_mut(LifetimeDef.bounds, strip);
+        visit_method_turbofish_mut(MethodTurbofish.args, strip);
+        visit_parenthesized_generic_arguments_mut(ParenthesizedGenericArguments.inputs, strip);
+        visit_partial_borrows_mut(PartialBorrows.borrows, strip);
+        visit_pat_slice_mut(PatSlice.elems, strip);
+        visit_pat_struct_mut(PatStruct.fields, strip);
+        visit_pat_tuple_mut(PatTuple.elems, strip_tuple);
+        visit_predicate_lifetime_mut(PredicateLifetime.bounds, strip);
+        visit_predicate_type_mut(PredicateType.bounds, strip);
+        visit_signature_mut(Signature.inputs, strip);
+        visit_trait_item_type_mut(TraitItemType.bounds, strip);
+        visit_type_bare_fn_mut(TypeBareFn.inputs, strip);
+        visit_type_impl_trait_mut(TypeImplTrait.bounds, strip);
+        visit_type_param_mut(TypeParam.bounds, strip);
+        visit_type_trait_object_mut(TypeTraitObject.bounds, strip);
+        visit_type_tuple_mut(TypeTuple.elems, strip_tuple);
+        visit_use_group_mut(UseGroup.items, strip);
+        visit_where_clause_mut(WhereClause.predicates, strip);
+    }
+
+    // The parenthesized fields of a tuple struct pattern are an argument
+    // list rather than a tuple, so `S(a,)` is the same pattern as `S(a)`.
+    fn visit_pat_tuple_struct_mut(&mut self, node: &mut PatTupleStruct) {
+        crate::visit_mut::visit_pat_tuple_struct_mut(self, node);
+        StripTrailingPunct::strip(&mut node.pat.elems);
+    }
+}
+
 #[cfg(feature = "extra-traits")]
 impl Eq for ItemMacro2 {}
 
@@ -464,6 +1179,246 @@ impl Hash for ItemMacro2 {
     }
 }
 
+impl Item {
+    /// Whether the item is a struct or enum annotated `#[non_exhaustive]`.
+    pub fn is_non_exhaustive(&self) -> bool {
+        match self {
+            Item::Struct(item) => item.is_non_exhaustive(),
+            Item::Enum(item) => item.is_non_exhaustive(),
+            _ => false,
+        }
+    }
+
+    /// The kind of the item, without its contents.
+    pub fn kind(&self) -> ItemKind {
+        match self {
+            Item::Const(_) => ItemKind::Const,
+            Item::Enum(_) => ItemKind::Enum,
+            Item::Existential(_) => ItemKind::Existential,
+            Item::ExternCrate(_) => ItemKind::ExternCrate,
+            Item::Fn(_) => ItemKind::Fn,
+            Item::ForeignMod(_) => ItemKind::ForeignMod,
+            Item::Impl(_) => ItemKind::Impl,
+            Item::Macro(_) => ItemKind::Macro,
+            Item::Macro2(_) => ItemKind::Macro2,
+            Item::Mod(_) => ItemKind::Mod,
+            Item::Static(_) => ItemKind::Static,
+            Item::Struct(_) => ItemKind::Struct,
+            Item::Trait(_) => ItemKind::Trait,
+            Item::TraitAlias(_) => ItemKind::TraitAlias,
+            Item::Type(_) => ItemKind::Type,
+            Item::Union(_) => ItemKind::Union,
+            Item::Use(_) => ItemKind::Use,
+            Item::Verbatim(_) => ItemKind::Verbatim,
+            Item::__Nonexhaustive => ItemKind::__Nonexhaustive,
+        }
+    }
+
+    /// Mutable access to the item's visibility, or `None` for item kinds
+    /// that do not have one, like impl blocks, foreign modules and macro
+    /// invocations.
+    pub fn vis_mut(&mut self) -> Option<&mut Visibility> {
+        match self {
+            Item::Const(item) => Some(&mut item.vis),
+            Item::Enum(item) => Some(&mut item.vis),
+            Item::Existential(item) => Some(&mut item.vis),
+            Item::ExternCrate(item) => Some(&mut item.vis),
+            Item::Fn(item) => Some(&mut item.vis),
+            Item::Macro2(item) => Some(&mut item.vis),
+            Item::Mod(item) => Some(&mut item.vis),
+            Item::Static(item) => Some(&mut item.vis),
+            Item::Struct(item) => Some(&mut item.vis),
+            Item::Trait(item) => Some(&mut item.vis),
+            Item::TraitAlias(item) => Some(&mut item.vis),
+            Item::Type(item) => Some(&mut item.vis),
+            Item::Union(item) => Some(&mut item.vis),
+            Item::Use(item) => Some(&mut item.vis),
+            Item::ForeignMod(_)
+            | Item::Impl(_)
+            | Item::Macro(_)
+            | Item::Verbatim(_)
+            | Item::__Nonexhaustive => None,
+        }
+    }
+
+    /// Sets the item's visibility to `pub`, if it has a visibility.
+    pub fn make_pub(&mut self) {
+        if let Some(vis) = self.vis_mut() {
+            *vis = Visibility::Public(VisPublic {
+                pub_token: Default::default(),
+            });
+        }
+    }
+
+    /// Renames the item, returning false and leaving the item unchanged if it
+    /// has no name: impl blocks, foreign modules, `use` items, macro
+    /// invocations other than `macro_rules!` definitions, and verbatim items.
+    pub fn set_ident(&mut self, ident: Ident) -> bool {
+        let slot = match self {
+            Item::Const(item) => &mut item.ident,
+            Item::Enum(item) => &mut item.ident,
+            Item::Existential(item) => &mut item.ident,
+            Item::ExternCrate(item) => &mut item.ident,
+            Item::Fn(item) => &mut item.sig.ident,
+            Item::Macro(ItemMacro {
+                ident: Some(name), ..
+            }) => name,
+            Item::Macro2(item) => &mut item.ident,
+            Item::Mod(item) => &mut item.ident,
+            Item::Static(item) => &mut item.ident,
+            Item::Struct(item) => &mut item.ident,
+            Item::Trait(item) => &mut item.ident,
+            Item::TraitAlias(item) => &mut item.ident,
+            Item::Type(item) => &mut item.ident,
+            Item::Union(item) => &mut item.ident,
+            Item::ForeignMod(_)
+            | Item::Impl(_)
+            | Item::Macro(_)
+            | Item::Use(_)
+            | Item::Verbatim(_)
+            | Item::__Nonexhaustive => return false,
+        };
+        *slot = ident;
+        true
+    }
+
+    /// Whether the item carries a `#[cfg(...)]` attribute.
+    ///
+    /// Attributes nested inside `#[cfg_attr(...)]` are not considered.
+    pub fn has_cfg(&self) -> bool {
+        has_attr(self.attrs(), "cfg")
+    }
+
+    /// The doc comment of the item, with the lines of all its `///` comments
+    /// and `#[doc = "..."]` attributes joined as by [`collect_doc`].
+    ///
+    /// Returns `None` if the item has no doc comment.
+    ///
+    /// [`collect_doc`]: crate::collect_doc
+    ///
+    /// *This method is available if Syn is built with the `"full"` and
+    /// `"parsing"` features.*
+    #[cfg(feature = "parsing")]
+    pub fn doc(&self) -> Option<String> {
+        let doc = crate::attr::collect_doc(self.attrs());
+        if doc.is_empty() {
+            None
+        } else {
+            Some(doc)
+        }
+    }
+
+    // Empty for verbatim items, whose attributes are not parsed.
+    fn attrs(&self) -> &[Attribute] {
+        match self {
+            Item::Const(item) => &item.attrs,
+            Item::Enum(item) => &item.attrs,
+            Item::Existential(item) => &item.attrs,
+            Item::ExternCrate(item) => &item.attrs,
+            Item::Fn(item) => &item.attrs,
+            Item::ForeignMod(item) => &item.attrs,
+            Item::Impl(item) => &item.attrs,
+            Item::Macro(item) => &item.attrs,
+            Item::Macro2(item) => &item.attrs,
+            Item::Mod(item) => &item.attrs,
+            Item::Static(item) => &item.attrs,
+            Item::Struct(item) => &item.attrs,
+            Item::Trait(item) => &item.attrs,
+            Item::TraitAlias(item) => &item.attrs,
+            Item::Type(item) => &item.attrs,
+            Item::Union(item) => &item.attrs,
+            Item::Use(item) => &item.attrs,
+            Item::Verbatim(_) | Item::__Nonexhaustive => &[],
+        }
+    }
+
+    /// Calls `f` on every item nested within this one, in source order,
+    /// not including the item itself.
+    ///
+    /// Items inside inline modules are always visited. Items declared at
+    /// the top level of a function body, whether a free function, an impl
+    /// method or a trait method with a default body, are visited only if
+    /// `fn_bodies` is true. Nested items are searched recursively.
+    pub fn for_each_nested_item<F>(&self, fn_bodies: bool, mut f: F)
+    where
+        F: FnMut(&Item),
+    {
+        for_each_nested_item(self, fn_bodies, &mut f);
+    }
+}
+
+fn for_each_nested_item(item: &Item, fn_bodies: bool, f: &mut dyn FnMut(&Item)) {
+    fn block(block: &Block, fn_bodies: bool, f: &mut dyn FnMut(&Item)) {
+        for stmt in &block.stmts {
+            if let Stmt::Item(item) = stmt {
+                f(item);
+                for_each_nested_item(item, fn_bodies, f);
+            }
+        }
+    }
+
+    match item {
+        Item::Mod(item) => {
+            if let Some((_, items)) = &item.content {
+                for item in items {
+                    f(item);
+                    for_each_nested_item(item, fn_bodies, f);
+                }
+            }
+        }
+        Item::Fn(item) if fn_bodies => block(&item.block, fn_bodies, f),
+        Item::Impl(item) if fn_bodies => {
+            for impl_item in &item.items {
+                if let ImplItem::Method(method) = impl_item {
+                    block(&method.block, fn_bodies, f);
+                }
+            }
+        }
+        Item::Trait(item) if fn_bodies => {
+            for trait_item in &item.items {
+                if let TraitItem::Method(method) = trait_item {
+                    if let Some(default) = &method.default {
+                        block(default, fn_bodies, f);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The kind of an [`Item`], with one variant per variant of `Item` and no
+/// data.
+///
+/// This is cheap to copy and compare, making it usable as a map key for
+/// grouping items by kind.
+///
+/// *This type is available if Syn is built with the `"full"` feature.*
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ItemKind {
+    Const,
+    Enum,
+    Existential,
+    ExternCrate,
+    Fn,
+    ForeignMod,
+    Impl,
+    Macro,
+    Macro2,
+    Mod,
+    Static,
+    Struct,
+    Trait,
+    TraitAlias,
+    Type,
+    Union,
+    Use,
+    Verbatim,
+
+    #[doc(hidden)]
+    __Nonexhaustive,
+}
+
 impl From<DeriveInput> for Item {
     fn from(input: DeriveInput) -> Item {
         match input.data {
@@ -586,43 +1541,266 @@ ast_struct! {
     }
 }
 
-ast_struct! {
-    /// An identifier imported by a `use` item: `HashMap`.
-    ///
-    /// *This type is available if Syn is built with the `"full"` feature.*
-    pub struct UseName {
-        pub ident: Ident,
+ast_struct! {
+    /// An identifier imported by a `use` item: `HashMap`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct UseName {
+        pub ident: Ident,
+    }
+}
+
+ast_struct! {
+    /// An renamed identifier imported by a `use` item: `HashMap as Map`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct UseRename {
+        pub ident: Ident,
+        pub as_token: Token![as],
+        pub rename: Ident,
+    }
+}
+
+ast_struct! {
+    /// A glob import in a `use` item: `*`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct UseGlob {
+        pub star_token: Token![*],
+    }
+}
+
+ast_struct! {
+    /// A braced group of imports in a `use` item: `{A, B, C}`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct UseGroup {
+        pub brace_token: token::Brace,
+        pub items: Punctuated<UseTree, Token![,]>,
+    }
+}
+
+ast_enum! {
+    /// A use tree whose braced groups may have outer attributes on their
+    /// members, as produced by [`UseTree::parse_with_attrs`].
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub enum UseTreeWithAttrs {
+        /// A path prefix of imports: `std::...`.
+        Path(Ident, Token![::], Box<UseTreeWithAttrs>),
+
+        /// A braced group of imports: `{#[cfg(x)] A, B}`.
+        Group(token::Brace, Punctuated<UseGroupEntry, Token![,]>),
+
+        /// An import that contains no group: a name, a rename or a glob.
+        Tree(UseTree),
+    }
+}
+
+ast_struct! {
+    /// A member of a braced group in a [`UseTreeWithAttrs`], together with
+    /// the outer attributes written in front of it.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct UseGroupEntry {
+        pub attrs: Vec<Attribute>,
+        pub tree: UseTreeWithAttrs,
+    }
+}
+
+impl UseTreeWithAttrs {
+    /// Converts into a plain [`UseTree`], dropping the attributes of every
+    /// group member.
+    pub fn into_use_tree(self) -> UseTree {
+        match self {
+            UseTreeWithAttrs::Path(ident, colon2_token, tree) => UseTree::Path(UsePath {
+                ident,
+                colon2_token,
+                tree: Box::new(tree.into_use_tree()),
+            }),
+            UseTreeWithAttrs::Group(brace_token, items) => UseTree::Group(UseGroup {
+                brace_token,
+                items: items
+                    .into_pairs()
+                    .map(|pair| {
+                        let (entry, comma) = pair.into_tuple();
+                        Pair::new(entry.tree.into_use_tree(), comma)
+                    })
+                    .collect(),
+            }),
+            UseTreeWithAttrs::Tree(tree) => tree,
+        }
+    }
+}
+
+impl UseTree {
+    /// Rewrites the tree into a canonical form, for example turning `a::{c,
+    /// b}` into `a::{b, c}` and `a::{b::{c}}` into `a::b::c`.
+    ///
+    /// Groups with a single member are replaced by that member, unless the
+    /// member is `self`. The members of the
+    /// remaining groups are sorted by their leading ident, with `self` first
+    /// and followed by globs and then nested groups. Renames and globs are
+    /// kept as they are.
+    pub fn normalize(self) -> UseTree {
+        match self {
+            UseTree::Path(mut path) => {
+                path.tree = Box::new(path.tree.normalize());
+                UseTree::Path(path)
+            }
+            UseTree::Group(mut group) => {
+                let mut items: Vec<UseTree> =
+                    group.items.into_iter().map(UseTree::normalize).collect();
+                if items.len() == 1 {
+                    match &items[0] {
+                        UseTree::Name(name) if name.ident == "self" => {}
+                        _ => return items.remove(0),
+                    }
+                }
+                items.sort_by(|a, b| use_tree_sort_key(a).cmp(&use_tree_sort_key(b)));
+                group.items = items.into_iter().collect();
+                UseTree::Group(group)
+            }
+            tree @ UseTree::Name(_) | tree @ UseTree::Rename(_) | tree @ UseTree::Glob(_) => tree,
+        }
+    }
+}
+
+fn use_tree_sort_key(tree: &UseTree) -> (u8, String) {
+    let ident = match tree {
+        UseTree::Path(tree) => &tree.ident,
+        UseTree::Name(tree) => &tree.ident,
+        UseTree::Rename(tree) => &tree.ident,
+        UseTree::Glob(_) => return (2, String::new()),
+        UseTree::Group(_) => return (3, String::new()),
+    };
+    if ident == "self" {
+        (0, String::new())
+    } else {
+        (1, ident.to_string())
     }
 }
 
-ast_struct! {
-    /// An renamed identifier imported by a `use` item: `HashMap as Map`.
-    ///
-    /// *This type is available if Syn is built with the `"full"` feature.*
-    pub struct UseRename {
-        pub ident: Ident,
-        pub as_token: Token![as],
-        pub rename: Ident,
+/// Merges `use` items that share a leading path into grouped imports, so
+/// that `use a::b; use a::c;` becomes `use a::{b, c};`.
+///
+/// Only items with identical attributes, visibility and leading `::` are
+/// merged. Renames, globs and nested paths are merged like plain names, so
+/// `use a::b as c; use a::d::*;` becomes `use a::{b as c, d::*};`, and an
+/// import that appears twice is kept once. The result has one item per
+/// distinct leading path segment, ordered by first appearance among the
+/// items with the same attributes and visibility.
+///
+/// *This function is available if Syn is built with the `"full"`,
+/// `"printing"` and `"clone-impls"` features.*
+#[cfg(all(feature = "printing", feature = "clone-impls"))]
+pub fn merge_use_items(items: &[ItemUse]) -> Vec<ItemUse> {
+    use quote::ToTokens;
+
+    let mut groups: Vec<(String, &ItemUse, Vec<UseTree>)> = Vec::new();
+    for item in items {
+        let mut key = TokenStream::new();
+        for attr in &item.attrs {
+            attr.to_tokens(&mut key);
+        }
+        item.vis.to_tokens(&mut key);
+        item.leading_colon.to_tokens(&mut key);
+        let key = key.to_string();
+        match groups.iter_mut().find(|group| group.0 == key) {
+            Some(group) => group.2.push(item.tree.clone()),
+            None => groups.push((key, item, vec![item.tree.clone()])),
+        }
     }
-}
 
-ast_struct! {
-    /// A glob import in a `use` item: `*`.
-    ///
-    /// *This type is available if Syn is built with the `"full"` feature.*
-    pub struct UseGlob {
-        pub star_token: Token![*],
+    let mut merged = Vec::new();
+    for (_, first, trees) in groups {
+        for tree in merge_use_trees(trees) {
+            merged.push(ItemUse {
+                attrs: first.attrs.clone(),
+                vis: first.vis.clone(),
+                use_token: first.use_token,
+                leading_colon: first.leading_colon,
+                tree,
+                semi_token: first.semi_token,
+            });
+        }
     }
+    merged
 }
 
-ast_struct! {
-    /// A braced group of imports in a `use` item: `{A, B, C}`.
-    ///
-    /// *This type is available if Syn is built with the `"full"` feature.*
-    pub struct UseGroup {
-        pub brace_token: token::Brace,
-        pub items: Punctuated<UseTree, Token![,]>,
+#[cfg(all(feature = "printing", feature = "clone-impls"))]
+fn merge_use_trees(trees: Vec<UseTree>) -> Vec<UseTree> {
+    fn flatten(tree: UseTree, flat: &mut Vec<UseTree>) {
+        match tree {
+            UseTree::Group(group) => {
+                for tree in group.items {
+                    flatten(tree, flat);
+                }
+            }
+            tree => flat.push(tree),
+        }
+    }
+
+    fn same_leaf(a: &UseTree, b: &UseTree) -> bool {
+        match (a, b) {
+            (UseTree::Name(a), UseTree::Name(b)) => a.ident == b.ident,
+            (UseTree::Rename(a), UseTree::Rename(b)) => a.ident == b.ident && a.rename == b.rename,
+            (UseTree::Glob(_), UseTree::Glob(_)) => true,
+            _ => false,
+        }
+    }
+
+    let mut flat = Vec::new();
+    for tree in trees {
+        flatten(tree, &mut flat);
+    }
+
+    // Each path is paired with the subtrees of later paths that share its
+    // leading ident.
+    let mut merged: Vec<(UseTree, Vec<UseTree>)> = Vec::new();
+    for tree in flat {
+        if let UseTree::Path(path) = &tree {
+            let prev = merged.iter_mut().find(|(prev, _)| match prev {
+                UseTree::Path(prev) => prev.ident == path.ident,
+                _ => false,
+            });
+            if let Some((_, subtrees)) = prev {
+                if let UseTree::Path(path) = tree {
+                    subtrees.push(*path.tree);
+                }
+                continue;
+            }
+        }
+        if merged.iter().any(|(prev, _)| same_leaf(prev, &tree)) {
+            continue;
+        }
+        merged.push((tree, Vec::new()));
     }
+
+    merged
+        .into_iter()
+        .map(|(tree, subtrees)| {
+            let mut path = match tree {
+                UseTree::Path(path) => path,
+                tree => return tree,
+            };
+            if subtrees.is_empty() {
+                return UseTree::Path(path);
+            }
+            let mut trees = vec![*path.tree];
+            trees.extend(subtrees);
+            let mut trees = merge_use_trees(trees);
+            path.tree = Box::new(if trees.len() == 1 {
+                trees.remove(0)
+            } else {
+                UseTree::Group(UseGroup {
+                    brace_token: token::Brace::default(),
+                    items: trees.into_iter().collect(),
+                })
+            });
+            UseTree::Path(path)
+        })
+        .collect()
 }
 
 ast_enum_of_structs! {
@@ -671,6 +1849,26 @@ ast_struct! {
     }
 }
 
+impl ForeignItemFn {
+    /// Checks that the signature is one a foreign function can have.
+    ///
+    /// Returns an error pointing at the offending keyword if the signature is
+    /// `const`, `async` or `gen`, which the parser never produces but a
+    /// programmatically constructed signature might contain.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(constness) = &self.sig.constness {
+            return Err(Error::new(constness.span, "foreign functions cannot be `const`"));
+        }
+        if let Some(asyncness) = &self.sig.asyncness {
+            return Err(Error::new(asyncness.span, "foreign functions cannot be `async`"));
+        }
+        if let Some(genness) = &self.sig.genness {
+            return Err(Error::new(genness.span, "foreign functions cannot be `gen`"));
+        }
+        Ok(())
+    }
+}
+
 ast_struct! {
     /// A foreign static item in an `extern` block: `static ext: u8`.
     ///
@@ -796,6 +1994,16 @@ ast_enum_of_structs! {
     }
 }
 
+impl TraitItem {
+    /// The signature of a method, or `None` for other kinds of item.
+    pub fn sig(&self) -> Option<&Signature> {
+        match self {
+            TraitItem::Method(item) => Some(&item.sig),
+            _ => None,
+        }
+    }
+}
+
 ast_struct! {
     /// An associated constant within the definition of a trait.
     ///
@@ -811,6 +2019,18 @@ ast_struct! {
     }
 }
 
+impl TraitItemConst {
+    /// The default value, the `3` in `const B: u8 = 3;`.
+    pub fn default_value(&self) -> Option<&Expr> {
+        self.default.as_ref().map(|(_, expr)| expr)
+    }
+
+    /// Whether the trait provides a default value, so that impls need not.
+    pub fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
+}
+
 ast_struct! {
     /// A trait method within the definition of a trait.
     ///
@@ -823,6 +2043,39 @@ ast_struct! {
     }
 }
 
+impl TraitItemMethod {
+    /// Whether the method is annotated `#[track_caller]`.
+    pub fn is_track_caller(&self) -> bool {
+        has_attr(&self.attrs, "track_caller")
+    }
+
+    /// Turns the trait method into a method of an impl block with the given
+    /// body and visibility, keeping its attributes and signature.
+    ///
+    /// Any default body of the trait method is discarded.
+    pub fn into_impl_method(self, block: Block, vis: Visibility) -> ImplItemMethod {
+        ImplItemMethod {
+            attrs: self.attrs,
+            vis,
+            defaultness: None,
+            sig: self.sig,
+            block,
+        }
+    }
+
+    /// Turns the trait method into a method of an impl block with the given
+    /// visibility, using the trait method's default body.
+    ///
+    /// Returns an error pointing at the method name if the trait method has
+    /// no default body.
+    pub fn into_impl_method_with_default_body(mut self, vis: Visibility) -> Result<ImplItemMethod> {
+        match self.default.take() {
+            Some(block) => Ok(self.into_impl_method(block, vis)),
+            None => Err(Error::new(self.sig.ident.span(), "trait method has no default body")),
+        }
+    }
+}
+
 ast_struct! {
     /// An associated type within the definition of a trait.
     ///
@@ -839,6 +2092,24 @@ ast_struct! {
     }
 }
 
+impl TraitItemType {
+    /// Whether this is a generic associated type, like `type Item<'a>;`.
+    pub fn is_gat(&self) -> bool {
+        !self.generics.params.is_empty()
+    }
+
+    /// The default type, the `u8` in `type Item: Clone = u8;`.
+    pub fn default_type(&self) -> Option<&Type> {
+        self.default.as_ref().map(|(_, ty)| ty)
+    }
+
+    /// The bounds an implementation's type must satisfy, the `Clone` in
+    /// `type Item: Clone = u8;`.
+    pub fn bounds_iter(&self) -> impl Iterator<Item = &TypeParamBound> {
+        self.bounds.iter()
+    }
+}
+
 ast_struct! {
     /// A macro invocation within the definition of a trait.
     ///
@@ -935,6 +2206,16 @@ ast_enum_of_structs! {
     }
 }
 
+impl ImplItem {
+    /// The signature of a method, or `None` for other kinds of item.
+    pub fn sig(&self) -> Option<&Signature> {
+        match self {
+            ImplItem::Method(item) => Some(&item.sig),
+            _ => None,
+        }
+    }
+}
+
 ast_struct! {
     /// An associated constant within an impl block.
     ///
@@ -966,6 +2247,23 @@ ast_struct! {
     }
 }
 
+impl ImplItemMethod {
+    /// Whether the method is annotated `#[track_caller]`.
+    pub fn is_track_caller(&self) -> bool {
+        has_attr(&self.attrs, "track_caller")
+    }
+
+    /// The statements of the method body.
+    pub fn stmts(&self) -> &[Stmt] {
+        &self.block.stmts
+    }
+
+    /// Mutable access to the statements of the method body.
+    pub fn stmts_mut(&mut self) -> &mut Vec<Stmt> {
+        &mut self.block.stmts
+    }
+}
+
 ast_struct! {
     /// An associated type within an impl block.
     ///
@@ -983,6 +2281,14 @@ ast_struct! {
     }
 }
 
+impl ImplItemType {
+    /// Whether this is a generic associated type, like `type Item<'a> =
+    /// &'a T;`.
+    pub fn is_gat(&self) -> bool {
+        !self.generics.params.is_empty()
+    }
+}
+
 ast_struct! {
     /// A macro invocation within an impl block.
     ///
@@ -994,6 +2300,49 @@ ast_struct! {
     }
 }
 
+/// Access to the macro invocation of any item kind that consists of one:
+/// [`ItemMacro`], [`ForeignItemMacro`], [`TraitItemMacro`] and
+/// [`ImplItemMacro`].
+///
+/// *This trait is available if Syn is built with the `"full"` feature.*
+///
+/// # Example
+///
+/// ```
+/// use syn::HasMacro;
+///
+/// fn is_todo<T: HasMacro>(item: &T) -> bool {
+///     item.mac().path.is_ident("todo")
+/// }
+/// ```
+pub trait HasMacro {
+    fn mac(&self) -> &Macro;
+}
+
+impl HasMacro for ItemMacro {
+    fn mac(&self) -> &Macro {
+        &self.mac
+    }
+}
+
+impl HasMacro for ForeignItemMacro {
+    fn mac(&self) -> &Macro {
+        &self.mac
+    }
+}
+
+impl HasMacro for TraitItemMacro {
+    fn mac(&self) -> &Macro {
+        &self.mac
+    }
+}
+
+impl HasMacro for ImplItemMacro {
+    fn mac(&self) -> &Macro {
+        &self.mac
+    }
+}
+
 #[cfg(feature = "extra-traits")]
 impl Eq for ImplItem {}
 
@@ -1053,6 +2402,7 @@ ast_struct! {
     pub struct Signature {
         pub constness: Option<Token![const]>,
         pub asyncness: Option<Token![async]>,
+        pub genness: Option<kw::gen>,
         pub unsafety: Option<Token![unsafe]>,
         pub abi: Option<Abi>,
         pub fn_token: Token![fn],
@@ -1060,27 +2410,171 @@ ast_struct! {
         pub generics: Generics,
         pub paren_token: token::Paren,
         pub inputs: Punctuated<FnArg, Token![,]>,
+        /// The `...` of a variadic function.
+        ///
+        /// A free function spells it as a final `args: ...` argument, which
+        /// also stays in `inputs` together with its attributes, leaving
+        /// `attrs` here empty. Use [`Signature::variadic_attrs`] to get the
+        /// attributes either way.
         pub variadic: Option<Variadic>,
         pub output: ReturnType,
     }
 }
 
+impl Default for Signature {
+    /// An empty signature `fn __()`, to be filled in field by field.
+    ///
+    /// The `ident` is a placeholder spanned at the call site, which the caller
+    /// is expected to overwrite.
+    fn default() -> Self {
+        Signature {
+            constness: None,
+            asyncness: None,
+            genness: None,
+            unsafety: None,
+            abi: None,
+            fn_token: Default::default(),
+            ident: Ident::new("__", Span::call_site()),
+            generics: Generics::default(),
+            paren_token: token::Paren::default(),
+            inputs: Punctuated::new(),
+            variadic: None,
+            output: ReturnType::Default,
+        }
+    }
+}
+
 impl Signature {
     /// A method's `self` receiver, such as `&self` or `self: Box<Self>`.
     pub fn receiver(&self) -> Option<&FnArg> {
         let arg = self.inputs.first()?;
-        match arg {
-            FnArg::Receiver(_) => Some(arg),
-            FnArg::Typed(PatType { pat, .. }) => {
-                if let Pat::Ident(PatIdent { ident, .. }) = &**pat {
-                    if ident == "self" {
-                        return Some(arg);
-                    }
-                }
-                None
-            }
+        if arg.is_self() {
+            Some(arg)
+        } else {
+            None
+        }
+    }
+
+    /// The fields borrowed by a partial-borrow receiver, like the `{a, mut b}`
+    /// in `fn f(self.{a, mut b})`.
+    ///
+    /// Returns `None` if the function has no receiver or takes `self` in any
+    /// other way.
+    pub fn receiver_partial_borrows(&self) -> Option<&PartialBorrows> {
+        match self.receiver()? {
+            FnArg::Receiver(Receiver {
+                reference: Reference::Partial(_, borrows),
+                ..
+            }) => Some(borrows),
+            _ => None,
+        }
+    }
+
+    /// Adds a lifetime parameter in front of the function's generic
+    /// parameters, turning `fn f(x: &u8)` into `fn f<'a>(x: &u8)`.
+    pub fn add_lifetime(&mut self, lifetime: Lifetime) {
+        let generics = &mut self.generics;
+        if generics.lt_token.is_none() {
+            generics.lt_token = Some(Default::default());
+        }
+        if generics.gt_token.is_none() {
+            generics.gt_token = Some(Default::default());
+        }
+        let param = GenericParam::Lifetime(LifetimeDef::new(lifetime));
+        generics.params.insert(0, param);
+    }
+
+    /// The name of the function's ABI: `Some("C")` for `extern "C" fn`, or
+    /// `None` if the function has no `extern`.
+    ///
+    /// A bare `extern fn` uses the C ABI, so its ABI name is `Some("C")` as
+    /// well.
+    pub fn abi_name(&self) -> Option<String> {
+        let abi = self.abi.as_ref()?;
+        match &abi.name {
+            Some(name) => Some(name.value()),
+            None => Some("C".to_owned()),
+        }
+    }
+
+    /// Whether the function uses an unwinding ABI like `extern "C-unwind"`,
+    /// recognized by the `-unwind` suffix of the ABI name.
+    pub fn is_unwind_abi(&self) -> bool {
+        match self.abi_name() {
+            Some(name) => name.ends_with("-unwind"),
+            None => false,
+        }
+    }
+
+    /// Whether the function is declared `async`.
+    ///
+    /// See [`has_async_trait_attr`] for methods made async by the
+    /// `#[async_trait]` attribute instead.
+    ///
+    /// [`has_async_trait_attr`]: crate::has_async_trait_attr
+    pub fn is_async(&self) -> bool {
+        self.asyncness.is_some()
+    }
+
+    /// Whether the function is a method, taking `self` in any form.
+    pub fn has_receiver(&self) -> bool {
+        self.receiver().is_some()
+    }
+
+    /// The number of arguments, not counting the receiver or the `...` of a
+    /// variadic function.
+    pub fn arity(&self) -> usize {
+        let count = self.inputs.iter().filter(|arg| !arg.is_self()).count();
+        if has_variadic(&self.inputs) {
+            count - 1
+        } else {
+            count
+        }
+    }
+
+    /// The attributes on the `...` of a variadic function, like the
+    /// `#[attr]` in `fn f(x: u8, #[attr] ...)`, or an empty slice if the
+    /// function is not variadic.
+    pub fn variadic_attrs(&self) -> &[Attribute] {
+        match self.inputs.last() {
+            Some(arg) if has_variadic(&self.inputs) => arg.attrs(),
+            _ => match &self.variadic {
+                Some(variadic) => &variadic.attrs,
+                None => &[],
+            },
+        }
+    }
+
+    /// Whether the function never returns, as declared by the never type in
+    /// `fn f() -> !`.
+    ///
+    /// Nominal uninhabited types like `Infallible` are not recognized.
+    pub fn is_diverging(&self) -> bool {
+        match &self.output {
+            ReturnType::Type(_, ty) => match **ty {
+                Type::Never(_) => true,
+                _ => false,
+            },
+            ReturnType::Default => false,
         }
     }
+
+    /// The number of fields borrowed by a partial `self.{a, mut b}`
+    /// receiver, or `None` if the method has no partial receiver.
+    pub fn borrowed_field_count(&self) -> Option<usize> {
+        self.receiver_partial_borrows().map(|borrows| borrows.borrows.len())
+    }
+
+    /// Prints the signature, including its generics, where clause and
+    /// variadic, as a string of tokens. Useful for debugging generated code.
+    ///
+    /// *This method is available if Syn is built with the `"printing"`
+    /// feature.*
+    #[cfg(feature = "printing")]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        quote::ToTokens::to_token_stream(self).to_string()
+    }
 }
 
 ast_enum_of_structs! {
@@ -1095,45 +2589,255 @@ ast_enum_of_structs! {
         /// Box<Self>`, are parsed as a `FnArg::Typed`.
         Receiver(Receiver),
 
-        /// A function argument accepted by pattern and type.
-        Typed(PatType),
+        /// A function argument accepted by pattern and type.
+        Typed(PatType),
+    }
+}
+
+impl FnArg {
+    /// Whether this is a `self` argument, either a [`Receiver`] or a typed
+    /// `self` such as `self: Box<Self>`.
+    pub fn is_self(&self) -> bool {
+        match self {
+            FnArg::Receiver(_) => true,
+            FnArg::Typed(PatType { pat, .. }) => match &**pat {
+                Pat::Ident(PatIdent { ident, .. }) => ident == "self",
+                _ => false,
+            },
+        }
+    }
+
+    /// The pattern and type of a typed argument, or `None` for a receiver.
+    pub fn as_typed(&self) -> Option<(&Pat, &Type)> {
+        match self {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(arg) => Some((&arg.pat, &arg.ty)),
+        }
+    }
+
+    /// Converts a typed argument into its [`PatType`], or returns `None` for
+    /// a receiver.
+    pub fn into_typed(self) -> Option<PatType> {
+        match self {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(arg) => Some(arg),
+        }
+    }
+
+    /// The attributes on the argument, like the `#[cfg(x)]` in `fn
+    /// f(#[cfg(x)] a: u8)`.
+    pub fn attrs(&self) -> &[Attribute] {
+        match self {
+            FnArg::Receiver(arg) => &arg.attrs,
+            FnArg::Typed(arg) => &arg.attrs,
+        }
+    }
+
+    /// Mutable access to the attributes on the argument.
+    pub fn attrs_mut(&mut self) -> &mut Vec<Attribute> {
+        match self {
+            FnArg::Receiver(arg) => &mut arg.attrs,
+            FnArg::Typed(arg) => &mut arg.attrs,
+        }
+    }
+
+    /// A typed argument `pat: ty` with no attributes, its colon spanned at
+    /// the call site.
+    pub fn typed(pat: Pat, ty: Type) -> Self {
+        FnArg::Typed(PatType {
+            attrs: Vec::new(),
+            pat: Box::new(pat),
+            colon_token: Default::default(),
+            ty: Box::new(ty),
+        })
+    }
+
+    /// A `&self` receiver, or `&mut self` if `mutable` is true, with its
+    /// tokens spanned at the call site.
+    pub fn receiver_ref(mutable: bool) -> Self {
+        let mutability = if mutable {
+            Some(Default::default())
+        } else {
+            None
+        };
+        FnArg::Receiver(Receiver {
+            attrs: Vec::new(),
+            reference: Reference::Full(Default::default(), None, mutability),
+            self_token: Default::default(),
+        })
+    }
+}
+
+ast_enum! {
+    pub enum Reference {
+        None(Option<Token![mut]>),
+        Partial(Token![.], PartialBorrows),
+        Full(Token![&], Option<Lifetime>, Option<Token![mut]>),
+    }
+}
+
+ast_struct! {
+    /// The `self` argument of an associated method, whether taken by value
+    /// or by reference.
+    ///
+    /// Note that `self` receivers with a specified type, such as `self:
+    /// Box<Self>`, are parsed as a `FnArg::Typed`.
+    ///
+    /// *This type is available if Syn is built with the `"full"` feature.*
+    pub struct Receiver {
+        pub attrs: Vec<Attribute>,
+        pub reference: Reference,
+        pub self_token: Token![self],
+    }
+}
+
+impl Receiver {
+    /// Whether `self` is taken by value: `self` or `mut self`.
+    pub fn by_value(&self) -> bool {
+        match self.reference {
+            Reference::None(_) => true,
+            Reference::Partial(..) | Reference::Full(..) => false,
+        }
+    }
+
+    /// Whether `self` is taken by reference: `&self`, `&'a self` or `&mut
+    /// self`.
+    pub fn by_reference(&self) -> bool {
+        match self.reference {
+            Reference::Full(..) => true,
+            Reference::None(_) | Reference::Partial(..) => false,
+        }
+    }
+
+    /// Whether `self` is partially borrowed: `self.{a, mut b}`.
+    pub fn is_partial(&self) -> bool {
+        match self.reference {
+            Reference::Partial(..) => true,
+            Reference::None(_) | Reference::Full(..) => false,
+        }
+    }
+}
+
+macro_rules! impl_generic_param_counts {
+    ($($item:ident)*) => {$(
+        impl $item {
+            /// The number of lifetime parameters in `self.generics`.
+            pub fn lifetime_count(&self) -> usize {
+                self.generics.lifetimes().count()
+            }
+
+            /// The number of type parameters in `self.generics`.
+            pub fn type_param_count(&self) -> usize {
+                self.generics.type_params().count()
+            }
+
+            /// The number of const parameters in `self.generics`.
+            pub fn const_param_count(&self) -> usize {
+                self.generics.const_params().count()
+            }
+        }
+    )*};
+}
+
+impl_generic_param_counts!(ItemEnum ItemImpl ItemStruct ItemTrait ItemType);
+
+impl ItemFn {
+    /// The number of lifetime parameters in `self.sig.generics`.
+    pub fn lifetime_count(&self) -> usize {
+        self.sig.generics.lifetimes().count()
+    }
+
+    /// The number of type parameters in `self.sig.generics`.
+    pub fn type_param_count(&self) -> usize {
+        self.sig.generics.type_params().count()
+    }
+
+    /// The number of const parameters in `self.sig.generics`.
+    pub fn const_param_count(&self) -> usize {
+        self.sig.generics.const_params().count()
     }
 }
 
-ast_enum! {
-    pub enum Reference {
-        None(Option<Token![mut]>),
-        Partial(Token![.], PartialBorrows),
-        Full(Token![&], Option<Lifetime>, Option<Token![mut]>),
+// Calls `f` on every name, rename or glob in a use tree, along with the path
+// leading up to it.
+fn for_each_use_leaf<'a>(
+    tree: &'a UseTree,
+    path: &mut Vec<&'a Ident>,
+    f: &mut dyn FnMut(&[&'a Ident], &'a UseTree),
+) {
+    match tree {
+        UseTree::Path(tree) => {
+            path.push(&tree.ident);
+            for_each_use_leaf(&tree.tree, path, f);
+            path.pop();
+        }
+        UseTree::Name(_) | UseTree::Rename(_) | UseTree::Glob(_) => f(path, tree),
+        UseTree::Group(tree) => {
+            for tree in &tree.items {
+                for_each_use_leaf(tree, path, f);
+            }
+        }
     }
 }
 
-ast_struct! {
-    /// The `self` argument of an associated method, whether taken by value
-    /// or by reference.
-    ///
-    /// Note that `self` receivers with a specified type, such as `self:
-    /// Box<Self>`, are parsed as a `FnArg::Typed`.
-    ///
-    /// *This type is available if Syn is built with the `"full"` feature.*
-    pub struct Receiver {
-        pub attrs: Vec<Attribute>,
-        pub reference: Reference,
-        pub self_token: Token![self],
+// The name that a use tree leaf brings into scope, treating `a::{self}` as
+// `a`. Globs and `as _` imports bring no nameable item into scope.
+fn use_leaf_binding<'a>(path: &[&'a Ident], leaf: &'a UseTree) -> Option<&'a Ident> {
+    match leaf {
+        UseTree::Name(leaf) if leaf.ident == "self" => path.last().cloned(),
+        UseTree::Name(leaf) => Some(&leaf.ident),
+        UseTree::Rename(leaf) if leaf.rename == "_" => None,
+        UseTree::Rename(leaf) => Some(&leaf.rename),
+        _ => None,
     }
 }
 
+// Whether the last argument is the `...` of a variadic function, which
+// free functions keep among their inputs as a verbatim type.
+fn has_variadic(inputs: &Punctuated<FnArg, Token![,]>) -> bool {
+    let last = match inputs.last() {
+        Some(last) => last,
+        None => return false,
+    };
+
+    let pat = match last {
+        FnArg::Typed(pat) => pat,
+        FnArg::Receiver(_) => return false,
+    };
+
+    let tokens = match pat.ty.as_ref() {
+        Type::Verbatim(tokens) => tokens,
+        _ => return false,
+    };
+
+    tokens.to_string() == "..."
+}
+
+fn has_attr(attrs: &[Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| path_is_ident(&attr.path, name))
+}
+
+// Same as `Path::is_ident`, which is only available with the "parsing"
+// feature.
+fn path_is_ident(path: &Path, name: &str) -> bool {
+    path.leading_colon.is_none()
+        && path.segments.len() == 1
+        && path.segments[0].arguments.is_empty()
+        && path.segments[0].ident == name
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
 
+    use crate::buffer::Cursor;
     use crate::ext::IdentExt;
     use crate::parse::discouraged::Speculative;
     use crate::parse::{Parse, ParseStream, Result};
     use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenTree};
     use std::iter::{self, FromIterator};
-
-    crate::custom_keyword!(existential);
+    #[cfg(feature = "printing")]
+    use std::ops::Range;
 
     impl Parse for Item {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -1175,6 +2879,7 @@ pub mod parsing {
                     input.parse().map(Item::Const)
                 } else if lookahead.peek(Token![unsafe])
                     || lookahead.peek(Token![async])
+                    || lookahead.peek(kw::gen)
                     || lookahead.peek(Token![extern])
                     || lookahead.peek(Token![fn])
                 {
@@ -1199,20 +2904,38 @@ pub mod parsing {
                 } else {
                     Err(lookahead.error())
                 }
-            } else if lookahead.peek(Token![async]) || lookahead.peek(Token![fn]) {
+            } else if lookahead.peek(Token![async])
+                || ahead.peek(kw::gen)
+                    && (ahead.peek2(Token![unsafe])
+                        || ahead.peek2(Token![extern])
+                        || ahead.peek2(Token![fn]))
+                || lookahead.peek(Token![fn])
+            {
                 input.parse().map(Item::Fn)
             } else if lookahead.peek(Token![mod]) {
                 input.parse().map(Item::Mod)
             } else if lookahead.peek(Token![type]) {
                 input.parse().map(Item::Type)
-            } else if lookahead.peek(existential) {
-                input.call(item_existential).map(Item::Verbatim)
+            } else if lookahead.peek(kw::existential) {
+                input.parse().map(Item::Existential)
             } else if lookahead.peek(Token![struct]) {
                 input.parse().map(Item::Struct)
             } else if lookahead.peek(Token![enum]) {
                 input.parse().map(Item::Enum)
             } else if lookahead.peek(Token![union]) && ahead.peek2(Ident) {
                 input.parse().map(Item::Union)
+            } else if ahead.peek(Token![union])
+                && !ahead.peek2(Token![!])
+                && !ahead.peek2(Token![::])
+            {
+                // `union` is only a keyword when it begins a union definition.
+                // Anything else, like a call `union();`, is an expression
+                // and does not belong at item position.
+                ahead.parse::<Token![union]>()?;
+                Err(ahead.error(
+                    "expected a union name after `union`; \
+                     `union` used as an identifier cannot begin an item",
+                ))
             } else if lookahead.peek(Token![trait]) {
                 input.call(parse_trait_or_trait_alias)
             } else if lookahead.peek(Token![auto]) && ahead.peek2(Token![trait]) {
@@ -1246,6 +2969,7 @@ pub mod parsing {
                     Item::Mod(item) => &mut item.attrs,
                     Item::ForeignMod(item) => &mut item.attrs,
                     Item::Type(item) => &mut item.attrs,
+                    Item::Existential(item) => &mut item.attrs,
                     Item::Struct(item) => &mut item.attrs,
                     Item::Enum(item) => &mut item.attrs,
                     Item::Union(item) => &mut item.attrs,
@@ -1275,7 +2999,7 @@ pub mod parsing {
             let semi_token: Option<Token![;]> = if !delimiter.is_brace() {
                 Some(input.parse()?)
             } else {
-                None
+                input.parse()?
             };
             Ok(ItemMacro {
                 attrs,
@@ -1425,8 +3149,48 @@ pub mod parsing {
         }
     }
 
+    impl UseTree {
+        /// Parses a use tree in which each member of a braced group may be
+        /// preceded by outer attributes, as in `a::{#[cfg(x)] b, c}`.
+        ///
+        /// Rust does not accept attributes in this position, so the default
+        /// [`Parse`] impl rejects them and `UseTree` has no place to keep
+        /// them. The attributes end up in the [`UseGroupEntry`] of the member
+        /// they precede.
+        ///
+        /// [`Parse`]: crate::parse::Parse
+        pub fn parse_with_attrs(input: ParseStream) -> Result<UseTreeWithAttrs> {
+            let starts_path = (input.peek(Ident)
+                || input.peek(Token![self])
+                || input.peek(Token![super])
+                || input.peek(Token![crate])
+                || input.peek(Token![extern]))
+                && input.peek2(Token![::]);
+            if starts_path {
+                Ok(UseTreeWithAttrs::Path(
+                    input.call(Ident::parse_any)?,
+                    input.parse()?,
+                    Box::new(UseTree::parse_with_attrs(input)?),
+                ))
+            } else if input.peek(token::Brace) {
+                let content;
+                let brace_token = braced!(content in input);
+                let items = content.parse_terminated(|input| {
+                    Ok(UseGroupEntry {
+                        attrs: input.call(Attribute::parse_outer)?,
+                        tree: UseTree::parse_with_attrs(input)?,
+                    })
+                })?;
+                Ok(UseTreeWithAttrs::Group(brace_token, items))
+            } else {
+                input.parse().map(UseTreeWithAttrs::Tree)
+            }
+        }
+    }
+
     impl Parse for ItemStatic {
         fn parse(input: ParseStream) -> Result<Self> {
+            let begin = input.cursor();
             Ok(ItemStatic {
                 attrs: input.call(Attribute::parse_outer)?,
                 vis: input.parse()?,
@@ -1437,13 +3201,14 @@ pub mod parsing {
                 ty: input.parse()?,
                 eq_token: input.parse()?,
                 expr: input.parse()?,
-                semi_token: input.parse()?,
+                semi_token: semi_after(input, begin, "static item")?,
             })
         }
     }
 
     impl Parse for ItemConst {
         fn parse(input: ParseStream) -> Result<Self> {
+            let begin = input.cursor();
             Ok(ItemConst {
                 attrs: input.call(Attribute::parse_outer)?,
                 vis: input.parse()?,
@@ -1460,7 +3225,7 @@ pub mod parsing {
                 ty: input.parse()?,
                 eq_token: input.parse()?,
                 expr: input.parse()?,
-                semi_token: input.parse()?,
+                semi_token: semi_after(input, begin, "const item")?,
             })
         }
     }
@@ -1471,6 +3236,7 @@ pub mod parsing {
             let vis: Visibility = input.parse()?;
             let constness: Option<Token![const]> = input.parse()?;
             let asyncness: Option<Token![async]> = input.parse()?;
+            let genness: Option<kw::gen> = input.parse()?;
             let unsafety: Option<Token![unsafe]> = input.parse()?;
             let abi: Option<Abi> = input.parse()?;
             let fn_token: Token![fn] = input.parse()?;
@@ -1510,6 +3276,7 @@ pub mod parsing {
                 sig: Signature {
                     constness,
                     asyncness,
+                    genness,
                     unsafety,
                     abi,
                     fn_token,
@@ -1533,7 +3300,8 @@ pub mod parsing {
             let attrs = input.call(Attribute::parse_outer)?;
 
             let ahead = input.fork();
-            if let Ok(mut receiver) = ahead.parse::<Receiver>() {
+            if let Ok(mut receiver) = ahead.call(receiver) {
+                reject_partial_ref(&receiver, &ahead)?;
                 if !ahead.peek(Token![:]) {
                     input.advance_to(&ahead);
                     receiver.attrs = attrs;
@@ -1549,34 +3317,51 @@ pub mod parsing {
 
     impl Parse for Receiver {
         fn parse(input: ParseStream) -> Result<Self> {
-            let reference;
-            let self_token;
-            let lookahead = input.lookahead1();
-            if lookahead.peek(Token![mut]) {
-                reference = Reference::None(input.parse()?);
-                self_token = input.parse()?;
-            } else if lookahead.peek(Token![&]) {
-                reference = Reference::Full(
-                    input.parse()?,
+            let receiver = receiver(input)?;
+            reject_partial_ref(&receiver, input)?;
+            Ok(receiver)
+        }
+    }
+
+    // A `.` directly after `&self` would begin a partial borrow, which is not
+    // supported on reference receivers.
+    fn reject_partial_ref(receiver: &Receiver, input: ParseStream) -> Result<()> {
+        match receiver.reference {
+            Reference::Full(..) if input.peek(Token![.]) => {
+                Err(input.error("partial borrows cannot be combined with `&` receivers"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn receiver(input: ParseStream) -> Result<Receiver> {
+        let reference;
+        let self_token;
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![mut]) {
+            reference = Reference::None(input.parse()?);
+            self_token = input.parse()?;
+        } else if lookahead.peek(Token![&]) {
+            reference = Reference::Full(
+                input.parse()?,
+                input.parse()?,
+                input.parse()?,
+            );
+            self_token = input.parse()?;
+        } else if lookahead.peek(Token![self]) {
+            self_token = input.parse()?;
+            reference = if input.peek(Token![.]) {
+                Reference::Partial(
                     input.parse()?,
                     input.parse()?,
-                );
-                self_token = input.parse()?;
-            } else if lookahead.peek(Token![self]) {
-                self_token = input.parse()?;
-                reference = if input.peek(Token![.]) {
-                    Reference::Partial(
-                        input.parse()?,
-                        input.parse()?,
-                    )
-                } else {
-                    Reference::None(None)
-                };
+                )
             } else {
-                return Err(lookahead.error());
-            }
-            Ok(Receiver { attrs: Vec::new(), reference, self_token })
+                Reference::None(None)
+            };
+        } else {
+            return Err(lookahead.error());
         }
+        Ok(Receiver { attrs: Vec::new(), reference, self_token })
     }
 
     fn fn_arg_typed(input: ParseStream) -> Result<PatType> {
@@ -1596,7 +3381,7 @@ pub mod parsing {
             });
         }
 
-        Ok(PatType {
+        let typed = PatType {
             attrs: Vec::new(),
             pat: input.parse()?,
             colon_token: input.parse()?,
@@ -1617,7 +3402,23 @@ pub mod parsing {
                 }
                 None => input.parse()?,
             }),
-        })
+        };
+        reject_partial_typed_self(&typed, input)?;
+        Ok(typed)
+    }
+
+    // A `.` directly after the type of `self: Box<Self>` would begin a partial
+    // borrow, which is only supported on receivers without a type.
+    fn reject_partial_typed_self(typed: &PatType, input: ParseStream) -> Result<()> {
+        let is_self = match &*typed.pat {
+            Pat::Ident(pat) => pat.ident == "self",
+            _ => false,
+        };
+        if is_self && input.peek(Token![.]) {
+            Err(input.error("partial borrows cannot be combined with typed `self` receivers"))
+        } else {
+            Ok(())
+        }
     }
 
     impl Parse for ItemMod {
@@ -1669,10 +3470,7 @@ pub mod parsing {
             let content;
             let brace_token = braced!(content in input);
             let inner_attrs = content.call(Attribute::parse_inner)?;
-            let mut items = Vec::new();
-            while !content.is_empty() {
-                items.push(content.parse()?);
-            }
+            let items = content.call(parse_foreign_items)?;
 
             Ok(ItemForeignMod {
                 attrs: private::attrs(outer_attrs, inner_attrs),
@@ -1765,6 +3563,7 @@ pub mod parsing {
                 sig: Signature {
                     constness: None,
                     asyncness: None,
+                    genness: None,
                     unsafety: None,
                     abi: None,
                     fn_token,
@@ -1829,6 +3628,7 @@ pub mod parsing {
 
     impl Parse for ItemType {
         fn parse(input: ParseStream) -> Result<Self> {
+            let begin = input.cursor();
             Ok(ItemType {
                 attrs: input.call(Attribute::parse_outer)?,
                 vis: input.parse()?,
@@ -1841,7 +3641,8 @@ pub mod parsing {
                 },
                 eq_token: input.parse()?,
                 ty: input.parse()?,
-                semi_token: input.parse()?,
+                where_clause: input.parse()?,
+                semi_token: semi_after(input, begin, "type alias")?,
             })
         }
     }
@@ -1853,54 +3654,58 @@ pub mod parsing {
 
     #[cfg(feature = "printing")]
     fn item_existential(input: ParseStream) -> Result<TokenStream> {
-        use crate::attr::FilterAttrs;
-        use quote::{ToTokens, TokenStreamExt};
+        use quote::ToTokens;
 
-        let attrs = input.call(Attribute::parse_outer)?;
-        let vis: Visibility = input.parse()?;
-        let existential_token: existential = input.parse()?;
-        let type_token: Token![type] = input.parse()?;
-        let ident: Ident = input.parse()?;
+        let item: ItemExistential = input.parse()?;
+        Ok(item.into_token_stream())
+    }
 
-        let mut generics: Generics = input.parse()?;
-        generics.where_clause = input.parse()?;
+    impl Parse for ItemExistential {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let attrs = input.call(Attribute::parse_outer)?;
+            let vis: Visibility = input.parse()?;
+            let existential_token: kw::existential = input.parse()?;
+            let type_token: Token![type] = input.parse()?;
+            let ident: Ident = input.parse()?;
+
+            let mut generics: Generics = input.parse()?;
+            generics.where_clause = input.parse()?;
 
-        let colon_token: Token![:] = input.parse()?;
+            let colon_token: Token![:] = input.parse()?;
 
-        let mut bounds = Punctuated::new();
-        while !input.peek(Token![;]) {
-            if !bounds.is_empty() {
-                bounds.push_punct(input.parse::<Token![+]>()?);
+            let mut bounds = Punctuated::new();
+            while !input.peek(Token![;]) {
+                if !bounds.is_empty() {
+                    bounds.push_punct(input.parse()?);
+                }
+                bounds.push_value(input.parse()?);
             }
-            bounds.push_value(input.parse::<TypeParamBound>()?);
-        }
 
-        let semi_token: Token![;] = input.parse()?;
+            let semi_token: Token![;] = input.parse()?;
 
-        let mut tokens = TokenStream::new();
-        tokens.append_all(attrs.outer());
-        vis.to_tokens(&mut tokens);
-        existential_token.to_tokens(&mut tokens);
-        type_token.to_tokens(&mut tokens);
-        ident.to_tokens(&mut tokens);
-        generics.to_tokens(&mut tokens);
-        generics.where_clause.to_tokens(&mut tokens);
-        if !bounds.is_empty() {
-            colon_token.to_tokens(&mut tokens);
-            bounds.to_tokens(&mut tokens);
+            Ok(ItemExistential {
+                attrs,
+                vis,
+                existential_token,
+                type_token,
+                ident,
+                generics,
+                colon_token,
+                bounds,
+                semi_token,
+            })
         }
-        semi_token.to_tokens(&mut tokens);
-        Ok(tokens)
     }
 
     impl Parse for ItemStruct {
         fn parse(input: ParseStream) -> Result<Self> {
             let attrs = input.call(Attribute::parse_outer)?;
             let vis = input.parse::<Visibility>()?;
+            let begin = input.cursor();
             let struct_token = input.parse::<Token![struct]>()?;
             let ident = input.parse::<Ident>()?;
             let generics = input.parse::<Generics>()?;
-            let (where_clause, fields, semi_token) = derive::parsing::data_struct(input)?;
+            let (where_clause, fields, semi_token) = derive::parsing::data_struct(input, begin)?;
             Ok(ItemStruct {
                 attrs,
                 vis,
@@ -2041,10 +3846,7 @@ pub mod parsing {
 
         let content;
         let brace_token = braced!(content in input);
-        let mut items = Vec::new();
-        while !content.is_empty() {
-            items.push(content.parse()?);
-        }
+        let items = content.call(parse_trait_items)?;
 
         Ok(ItemTrait {
             attrs,
@@ -2087,6 +3889,7 @@ pub mod parsing {
         ident: Ident,
         mut generics: Generics,
     ) -> Result<ItemTraitAlias> {
+        let begin = input.cursor();
         let eq_token: Token![=] = input.parse()?;
 
         let mut bounds = Punctuated::new();
@@ -2095,14 +3898,14 @@ pub mod parsing {
                 break;
             }
             bounds.push_value(input.parse()?);
-            if input.peek(Token![where]) || input.peek(Token![;]) {
+            if !input.peek(Token![+]) {
                 break;
             }
             bounds.push_punct(input.parse()?);
         }
 
         generics.where_clause = input.parse()?;
-        let semi_token: Token![;] = input.parse()?;
+        let semi_token = semi_after(input, begin, "trait alias")?;
 
         Ok(ItemTraitAlias {
             attrs,
@@ -2128,6 +3931,7 @@ pub mod parsing {
                 if lookahead.peek(Ident) {
                     input.parse().map(TraitItem::Const)
                 } else if lookahead.peek(Token![async])
+                    || lookahead.peek(kw::gen)
                     || lookahead.peek(Token![unsafe])
                     || lookahead.peek(Token![extern])
                     || lookahead.peek(Token![fn])
@@ -2137,6 +3941,10 @@ pub mod parsing {
                     Err(lookahead.error())
                 }
             } else if lookahead.peek(Token![async])
+                || ahead.peek(kw::gen)
+                    && (ahead.peek2(Token![unsafe])
+                        || ahead.peek2(Token![extern])
+                        || ahead.peek2(Token![fn]))
                 || lookahead.peek(Token![unsafe])
                 || lookahead.peek(Token![extern])
                 || lookahead.peek(Token![fn])
@@ -2199,6 +4007,7 @@ pub mod parsing {
             let outer_attrs = input.call(Attribute::parse_outer)?;
             let constness: Option<Token![const]> = input.parse()?;
             let asyncness: Option<Token![async]> = input.parse()?;
+            let genness: Option<kw::gen> = input.parse()?;
             let unsafety: Option<Token![unsafe]> = input.parse()?;
             let abi: Option<Abi> = input.parse()?;
             let fn_token: Token![fn] = input.parse()?;
@@ -2231,6 +4040,7 @@ pub mod parsing {
                 sig: Signature {
                     constness,
                     asyncness,
+                    genness,
                     unsafety,
                     abi,
                     fn_token,
@@ -2328,6 +4138,7 @@ pub mod parsing {
             } else {
                 Generics::default()
             };
+            let constness: Option<Token![const]> = input.parse()?;
 
             let trait_ = {
                 // TODO: optimize using advance_to
@@ -2350,11 +4161,7 @@ pub mod parsing {
             let content;
             let brace_token = braced!(content in input);
             let inner_attrs = content.call(Attribute::parse_inner)?;
-
-            let mut items = Vec::new();
-            while !content.is_empty() {
-                items.push(content.parse()?);
-            }
+            let items = content.call(parse_impl_items)?;
 
             Ok(ItemImpl {
                 attrs: private::attrs(outer_attrs, inner_attrs),
@@ -2365,6 +4172,7 @@ pub mod parsing {
                     where_clause,
                     ..generics
                 },
+                constness,
                 trait_,
                 self_ty: Box::new(self_ty),
                 brace_token,
@@ -2395,6 +4203,7 @@ pub mod parsing {
                     input.parse().map(ImplItem::Const)
                 } else if lookahead.peek(Token![unsafe])
                     || lookahead.peek(Token![async])
+                    || lookahead.peek(kw::gen)
                     || lookahead.peek(Token![extern])
                     || lookahead.peek(Token![fn])
                 {
@@ -2404,13 +4213,20 @@ pub mod parsing {
                 }
             } else if lookahead.peek(Token![unsafe])
                 || lookahead.peek(Token![async])
+                || ahead.peek(kw::gen)
+                    && (ahead.peek2(Token![unsafe])
+                        || ahead.peek2(Token![extern])
+                        || ahead.peek2(Token![fn]))
                 || lookahead.peek(Token![extern])
                 || lookahead.peek(Token![fn])
             {
                 input.parse().map(ImplItem::Method)
             } else if lookahead.peek(Token![type]) {
                 input.parse().map(ImplItem::Type)
-            } else if vis.is_inherited() && defaultness.is_none() && lookahead.peek(existential) {
+            } else if vis.is_inherited()
+                && defaultness.is_none()
+                && lookahead.peek(kw::existential)
+            {
                 input.call(item_existential).map(ImplItem::Verbatim)
             } else if vis.is_inherited()
                 && defaultness.is_none()
@@ -2467,6 +4283,7 @@ pub mod parsing {
             let defaultness: Option<Token![default]> = input.parse()?;
             let constness: Option<Token![const]> = input.parse()?;
             let asyncness: Option<Token![async]> = input.parse()?;
+            let genness: Option<kw::gen> = input.parse()?;
             let unsafety: Option<Token![unsafe]> = input.parse()?;
             let abi: Option<Abi> = input.parse()?;
             let fn_token: Token![fn] = input.parse()?;
@@ -2492,6 +4309,7 @@ pub mod parsing {
                 sig: Signature {
                     constness,
                     asyncness,
+                    genness,
                     unsafety,
                     abi,
                     fn_token,
@@ -2556,21 +4374,195 @@ pub mod parsing {
         }
     }
 
-    impl MacroDelimiter {
-        fn is_brace(&self) -> bool {
-            match *self {
-                MacroDelimiter::Brace(_) => true,
-                MacroDelimiter::Paren(_) | MacroDelimiter::Bracket(_) => false,
+    fn semi_after(input: ParseStream, begin: Cursor, what: &str) -> Result<Token![;]> {
+        if input.peek(Token![;]) {
+            input.parse()
+        } else {
+            Err(derive::parsing::missing_semi(input, begin, what))
+        }
+    }
+
+    /// Parses impl items until the end of the input, as found between the
+    /// braces of an impl block.
+    ///
+    /// Intended for use with [`Parser::parse2`] or [`ParseBuffer::call`] on
+    /// input that has no surrounding braces, such as the body of an
+    /// attribute.
+    ///
+    /// [`Parser::parse2`]: crate::parse::Parser::parse2
+    /// [`ParseBuffer::call`]: crate::parse::ParseBuffer::call
+    pub fn parse_impl_items(input: ParseStream) -> Result<Vec<ImplItem>> {
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            items.push(input.parse()?);
+        }
+        Ok(items)
+    }
+
+    /// Parses trait items until the end of the input, as found between the
+    /// braces of a trait definition.
+    pub fn parse_trait_items(input: ParseStream) -> Result<Vec<TraitItem>> {
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            items.push(input.parse()?);
+        }
+        Ok(items)
+    }
+
+    /// Parses foreign items until the end of the input, as found between the
+    /// braces of an `extern` block.
+    pub fn parse_foreign_items(input: ParseStream) -> Result<Vec<ForeignItem>> {
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            items.push(input.parse()?);
+        }
+        Ok(items)
+    }
+
+    /// Parses the items of a source file, pairing each item with the range of
+    /// bytes in `src` that it was parsed from.
+    ///
+    /// Ranges are only recorded if Syn is built with the `"span-locations"`
+    /// feature, and not when called from a procedural macro on a stable
+    /// compiler, as the spans carry no location there. Otherwise every range
+    /// is `0..0`.
+    ///
+    /// *This function is available if Syn is built with the `"parsing"` and
+    /// `"printing"` features.*
+    #[cfg(feature = "printing")]
+    pub fn parse_items_with_spans(src: &str) -> Result<Vec<(Item, Range<usize>)>> {
+        use crate::parse::Parser;
+
+        fn parse_items(input: ParseStream) -> Result<Vec<Item>> {
+            let mut items = Vec::new();
+            while !input.is_empty() {
+                items.push(input.parse()?);
             }
+            Ok(items)
+        }
+
+        let items = parse_items.parse_str(src)?;
+
+        // Each line of the source with the byte offset it starts at.
+        let mut line_start = 0;
+        let lines: Vec<(usize, &str)> = src
+            .split('\n')
+            .map(|line| {
+                let start = line_start;
+                line_start += line.len() + 1;
+                (start, line)
+            })
+            .collect();
+
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                let range = item_byte_range(&item, &lines);
+                (item, range)
+            })
+            .collect())
+    }
+
+    #[cfg(all(feature = "printing", feature = "span-locations"))]
+    fn item_byte_range(item: &Item, lines: &[(usize, &str)]) -> Range<usize> {
+        use quote::ToTokens;
+
+        let mut tokens = item.to_token_stream().into_iter();
+        let first = match tokens.next() {
+            Some(first) => first,
+            None => return 0..0,
+        };
+        let last = tokens.last().unwrap_or_else(|| first.clone());
+        let start = byte_offset(lines, first.span().start());
+        let end = byte_offset(lines, last.span().end());
+        match (start, end) {
+            (Some(start), Some(end)) if start < end => start..end,
+            _ => 0..0,
         }
     }
+
+    #[cfg(all(feature = "printing", not(feature = "span-locations")))]
+    fn item_byte_range(_item: &Item, _lines: &[(usize, &str)]) -> Range<usize> {
+        0..0
+    }
+
+    // Line numbers are 1-based and columns count chars; line 0 means the span
+    // has no location.
+    #[cfg(all(feature = "printing", feature = "span-locations"))]
+    fn byte_offset(lines: &[(usize, &str)], location: proc_macro2::LineColumn) -> Option<usize> {
+        let (line_start, line) = lines.get(location.line.checked_sub(1)?)?;
+        let column = line
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain(iter::once(line.len()))
+            .nth(location.column)?;
+        Some(line_start + column)
+    }
+
+    macro_rules! impl_parse_str {
+        ($($(#[$attr:meta])* $item:ident)*) => {$(
+            impl $item {
+                $(#[$attr])*
+                pub fn parse_str(s: &str) -> Result<Self> {
+                    crate::parse_str(s)
+                }
+            }
+        )*};
+    }
+
+    impl_parse_str! {
+        /// Parses a function from a string of Rust code, equivalent to
+        /// `syn::parse_str::<ItemFn>(s)`.
+        ///
+        /// ```
+        /// let item = syn::ItemFn::parse_str("fn f() {}").unwrap();
+        /// assert_eq!(item.sig.ident, "f");
+        /// ```
+        ItemFn
+
+        /// Parses a struct from a string of Rust code, equivalent to
+        /// `syn::parse_str::<ItemStruct>(s)`.
+        ///
+        /// ```
+        /// let item = syn::ItemStruct::parse_str("struct S(u8);").unwrap();
+        /// assert_eq!(item.ident, "S");
+        /// ```
+        ItemStruct
+
+        /// Parses an enum from a string of Rust code, equivalent to
+        /// `syn::parse_str::<ItemEnum>(s)`.
+        ///
+        /// ```
+        /// let item = syn::ItemEnum::parse_str("enum E { A, B }").unwrap();
+        /// assert_eq!(item.variants.len(), 2);
+        /// ```
+        ItemEnum
+
+        /// Parses an impl block from a string of Rust code, equivalent to
+        /// `syn::parse_str::<ItemImpl>(s)`.
+        ///
+        /// ```
+        /// let item = syn::ItemImpl::parse_str("impl Trait for S {}").unwrap();
+        /// assert!(item.trait_.is_some());
+        /// ```
+        ItemImpl
+
+        /// Parses a trait from a string of Rust code, equivalent to
+        /// `syn::parse_str::<ItemTrait>(s)`.
+        ///
+        /// ```
+        /// let item = syn::ItemTrait::parse_str("trait T { fn f(); }").unwrap();
+        /// assert_eq!(item.items.len(), 1);
+        /// ```
+        ItemTrait
+    }
 }
 
 #[cfg(feature = "printing")]
 mod printing {
     use super::*;
 
-    use proc_macro2::TokenStream;
+    use proc_macro2::{Spacing, TokenStream};
     use quote::{ToTokens, TokenStreamExt};
 
     use crate::attr::FilterAttrs;
@@ -2681,6 +4673,22 @@ mod printing {
             self.generics.where_clause.to_tokens(tokens);
             self.eq_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
+            self.where_clause.to_tokens(tokens);
+            self.semi_token.to_tokens(tokens);
+        }
+    }
+
+    impl ToTokens for ItemExistential {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.attrs.outer());
+            self.vis.to_tokens(tokens);
+            self.existential_token.to_tokens(tokens);
+            self.type_token.to_tokens(tokens);
+            self.ident.to_tokens(tokens);
+            self.generics.to_tokens(tokens);
+            self.generics.where_clause.to_tokens(tokens);
+            self.colon_token.to_tokens(tokens);
+            self.bounds.to_tokens(tokens);
             self.semi_token.to_tokens(tokens);
         }
     }
@@ -2777,6 +4785,7 @@ mod printing {
             self.unsafety.to_tokens(tokens);
             self.impl_token.to_tokens(tokens);
             self.generics.to_tokens(tokens);
+            self.constness.to_tokens(tokens);
             if let Some((polarity, path, for_token)) = &self.trait_ {
                 polarity.to_tokens(tokens);
                 path.to_tokens(tokens);
@@ -2858,6 +4867,31 @@ mod printing {
         }
     }
 
+    impl ToTokens for UseTreeWithAttrs {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                UseTreeWithAttrs::Path(ident, colon2_token, tree) => {
+                    ident.to_tokens(tokens);
+                    colon2_token.to_tokens(tokens);
+                    tree.to_tokens(tokens);
+                }
+                UseTreeWithAttrs::Group(brace_token, items) => {
+                    brace_token.surround(tokens, |tokens| {
+                        items.to_tokens(tokens);
+                    });
+                }
+                UseTreeWithAttrs::Tree(tree) => tree.to_tokens(tokens),
+            }
+        }
+    }
+
+    impl ToTokens for UseGroupEntry {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.attrs.outer());
+            self.tree.to_tokens(tokens);
+        }
+    }
+
     impl ToTokens for TraitItemConst {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             tokens.append_all(self.attrs.outer());
@@ -3009,29 +5043,11 @@ mod printing {
         }
     }
 
-    fn has_variadic(inputs: &Punctuated<FnArg, Token![,]>) -> bool {
-        let last = match inputs.last() {
-            Some(last) => last,
-            None => return false,
-        };
-
-        let pat = match last {
-            FnArg::Typed(pat) => pat,
-            FnArg::Receiver(_) => return false,
-        };
-
-        let tokens = match pat.ty.as_ref() {
-            Type::Verbatim(tokens) => tokens,
-            _ => return false,
-        };
-
-        tokens.to_string() == "..."
-    }
-
     impl ToTokens for Signature {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             self.constness.to_tokens(tokens);
             self.asyncness.to_tokens(tokens);
+            self.genness.to_tokens(tokens);
             self.unsafety.to_tokens(tokens);
             self.abi.to_tokens(tokens);
             self.fn_token.to_tokens(tokens);
@@ -3051,26 +5067,162 @@ mod printing {
         }
     }
 
-    impl ToTokens for Receiver {
-        fn to_tokens(&self, tokens: &mut TokenStream) {
-            tokens.append_all(self.attrs.outer());
-            match &self.reference {
+    impl Reference {
+        /// Prints the borrow part of a receiver, everything except the
+        /// `self` token: the `mut` in `mut self`, the `&'a mut` in `&'a mut
+        /// self` or the `.{a, mut b}` in `self.{a, mut b}`.
+        ///
+        /// Note that the tokens of a partial borrow follow `self` rather than
+        /// precede it.
+        pub fn to_prefix_tokens(&self, tokens: &mut TokenStream) {
+            match self {
                 Reference::None(mutability) => {
                     mutability.to_tokens(tokens);
-                    self.self_token.to_tokens(tokens);
-                },
+                }
                 Reference::Partial(dot, partial_borrows) => {
-                    self.self_token.to_tokens(tokens);
                     dot.to_tokens(tokens);
                     partial_borrows.to_tokens(tokens);
-                },
+                }
                 Reference::Full(ampersand, lifetime, mutability) => {
                     ampersand.to_tokens(tokens);
                     lifetime.to_tokens(tokens);
                     mutability.to_tokens(tokens);
+                }
+            }
+        }
+    }
+
+    impl ToTokens for Receiver {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            tokens.append_all(self.attrs.outer());
+            match &self.reference {
+                Reference::Partial(..) => {
                     self.self_token.to_tokens(tokens);
+                    self.reference.to_prefix_tokens(tokens);
+                }
+                Reference::None(_) | Reference::Full(..) => {
+                    self.reference.to_prefix_tokens(tokens);
+                    self.self_token.to_tokens(tokens);
+                }
+            }
+        }
+    }
+
+    impl Item {
+        /// Prints the item as Rust source spread over multiple lines, for
+        /// human-readable dumps while debugging.
+        ///
+        /// Unlike the compact output of [`ToTokens`], this starts a new line
+        /// after every `{`, before every `}` and after every statement, item
+        /// and outer attribute within braces, indenting the contents of each
+        /// brace by four spaces. Tokens are otherwise spaced as in the
+        /// `Display` output of a [`TokenStream`]. The result is not meant to
+        /// match rustfmt.
+        ///
+        /// *This method is available if Syn is built with the `"full"` and
+        /// `"printing"` features.*
+        pub fn to_pretty_string(&self) -> String {
+            let mut printer = PrettyPrinter {
+                out: String::new(),
+                indent: 0,
+            };
+            printer.print_lines(self.to_token_stream());
+            printer.out
+        }
+    }
+
+    struct PrettyPrinter {
+        out: String,
+        indent: usize,
+    }
+
+    impl PrettyPrinter {
+        // Prints the contents of braces, one statement or item per line.
+        fn print_lines(&mut self, tokens: TokenStream) {
+            let mut tokens = tokens.into_iter().peekable();
+            let mut prev_pound = false;
+            let mut space = false;
+            while let Some(token) = tokens.next() {
+                let ends_line = match &token {
+                    TokenTree::Punct(punct) => punct.as_char() == ';',
+                    TokenTree::Group(group) => match group.delimiter() {
+                        Delimiter::Bracket => prev_pound,
+                        Delimiter::Brace => match tokens.peek() {
+                            Some(TokenTree::Punct(_)) => false,
+                            Some(TokenTree::Ident(ident)) => ident != "else",
+                            _ => true,
+                        },
+                        Delimiter::Parenthesis | Delimiter::None => false,
+                    },
+                    TokenTree::Ident(_) | TokenTree::Literal(_) => false,
+                };
+                prev_pound = match &token {
+                    TokenTree::Punct(punct) => {
+                        punct.as_char() == '#' || prev_pound && punct.as_char() == '!'
+                    }
+                    _ => false,
+                };
+                space = self.print_token(token, space);
+                if ends_line && tokens.peek().is_some() {
+                    self.newline();
+                    space = false;
+                }
+            }
+        }
+
+        fn print_inline(&mut self, tokens: TokenStream) {
+            let mut space = false;
+            for token in tokens {
+                space = self.print_token(token, space);
+            }
+        }
+
+        // Returns whether the next token needs to be separated by a space.
+        fn print_token(&mut self, token: TokenTree, space: bool) -> bool {
+            if space {
+                self.out.push(' ');
+            }
+            match token {
+                TokenTree::Group(group) => {
+                    self.print_group(&group);
+                    true
+                }
+                TokenTree::Punct(punct) => {
+                    self.out.push(punct.as_char());
+                    punct.spacing() == Spacing::Alone
+                }
+                TokenTree::Ident(_) | TokenTree::Literal(_) => {
+                    self.out.push_str(&token.to_string());
+                    true
                 }
             }
         }
+
+        fn print_group(&mut self, group: &Group) {
+            let (open, close) = match group.delimiter() {
+                Delimiter::Parenthesis => ("(", ")"),
+                Delimiter::Bracket => ("[", "]"),
+                Delimiter::Brace => ("{", "}"),
+                Delimiter::None => ("", ""),
+            };
+            self.out.push_str(open);
+            if group.delimiter() == Delimiter::Brace && !group.stream().is_empty() {
+                self.indent += 1;
+                self.newline();
+                self.print_lines(group.stream());
+                self.indent -= 1;
+                self.newline();
+            } else {
+                self.print_inline(group.stream());
+            }
+            self.out.push_str(close);
+        }
+
+        fn newline(&mut self) {
+            self.out.push('\n');
+            for _ in 0..self.indent {
+                self.out.push_str("    ");
+            }
+        }
     }
 }
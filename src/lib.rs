@@ -240,6 +240,8 @@
 //!   types.
 //! - **`proc-macro`** *(enabled by default)* — Runtime dependency on the
 //!   dynamic library libproc_macro from rustc toolchain.
+//! - **`span-locations`** — Source locations for spans, used to record the
+//!   byte ranges of items parsed by `parse_items_with_spans`.
 
 // Syn types in rustdoc of other crates get linked to here.
 #![doc(html_root_url = "https://docs.rs/syn/1.0.7")]
@@ -307,8 +309,11 @@ pub use crate::ident::Ident;
 mod attr;
 #[cfg(any(feature = "full", feature = "derive"))]
 pub use crate::attr::{
-    AttrStyle, Attribute, AttributeArgs, Meta, MetaList, MetaNameValue, NestedMeta,
+    has_async_trait_attr, AttrStyle, Attribute, AttributeArgs, Meta, MetaList, MetaNameValue,
+    NestedMeta,
 };
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
+pub use crate::attr::{collect_doc, is_public_api};
 
 #[cfg(any(feature = "full", feature = "derive"))]
 mod bigint;
@@ -353,18 +358,30 @@ mod item;
 #[cfg(feature = "full")]
 pub use crate::item::{
     FnArg, ForeignItem, ForeignItemFn, ForeignItemMacro, ForeignItemStatic, ForeignItemType,
-    ImplItem, ImplItemConst, ImplItemMacro, ImplItemMethod, ImplItemType, Item, ItemConst,
-    ItemEnum, ItemExternCrate, ItemFn, ItemForeignMod, ItemImpl, ItemMacro, ItemMacro2, ItemMod,
-    ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType, ItemUnion, ItemUse, Receiver,
-    Reference, Signature, TraitItem, TraitItemConst, TraitItemMacro, TraitItemMethod,
-    TraitItemType, UseGlob, UseGroup, UseName, UsePath, UseRename, UseTree,
+    HasMacro, ImplItem, ImplItemConst, ImplItemMacro, ImplItemMethod, ImplItemType, Item, ItemConst,
+    ItemEnum, ItemExistential, ItemExternCrate, ItemFn, ItemForeignMod, ItemImpl, ItemMacro,
+    ItemKind, ItemMacro2, ItemMod, ItemStatic, ItemStruct, ItemTrait, ItemTraitAlias, ItemType,
+    ItemUnion, ItemUse, Receiver, Reference, Signature, TraitItem, TraitItemConst, TraitItemMacro,
+    TraitItemMethod, TraitItemType, UseGlob, UseGroup, UseGroupEntry, UseName, UsePath, UseRename,
+    UseTree, UseTreeWithAttrs,
 };
+#[cfg(all(feature = "full", feature = "parsing"))]
+pub use crate::item::parsing::{parse_foreign_items, parse_impl_items, parse_trait_items};
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub use crate::item::parsing::parse_items_with_spans;
+#[cfg(all(feature = "full", feature = "printing", feature = "clone-impls"))]
+pub use crate::item::merge_use_items;
 
 #[cfg(feature = "full")]
 mod partial_borrows;
 #[cfg(feature = "full")]
 pub use crate::partial_borrows::{PartialBorrow, PartialBorrows};
 
+#[cfg(all(feature = "full", feature = "printing"))]
+mod cached;
+#[cfg(all(feature = "full", feature = "printing"))]
+pub use crate::cached::CachedItem;
+
 #[cfg(feature = "full")]
 mod file;
 #[cfg(feature = "full")]
@@ -36,6 +36,35 @@ ast_enum! {
     }
 }
 
+impl MacroDelimiter {
+    /// Whether the delimiter is parentheses: `m!(...)`.
+    pub fn is_paren(&self) -> bool {
+        match self {
+            MacroDelimiter::Paren(_) => true,
+            MacroDelimiter::Brace(_) | MacroDelimiter::Bracket(_) => false,
+        }
+    }
+
+    /// Whether the delimiter is curly braces: `m! {...}`.
+    ///
+    /// Macro invocations in item position need a trailing semicolon unless
+    /// they are delimited by braces.
+    pub fn is_brace(&self) -> bool {
+        match self {
+            MacroDelimiter::Brace(_) => true,
+            MacroDelimiter::Paren(_) | MacroDelimiter::Bracket(_) => false,
+        }
+    }
+
+    /// Whether the delimiter is square brackets: `m![...]`.
+    pub fn is_bracket(&self) -> bool {
+        match self {
+            MacroDelimiter::Bracket(_) => true,
+            MacroDelimiter::Paren(_) | MacroDelimiter::Brace(_) => false,
+        }
+    }
+}
+
 #[cfg(feature = "extra-traits")]
 impl Eq for Macro {}
 
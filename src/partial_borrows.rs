@@ -1,5 +1,7 @@
 use crate::punctuated::Punctuated;
 use super::*;
+use std::iter::FromIterator;
+use std::mem;
 
 ast_struct! {
     pub struct PartialBorrow {
@@ -10,11 +12,98 @@ ast_struct! {
 
 ast_struct! {
     pub struct PartialBorrows {
+        /// A `mut` before the braces, as in `self.mut{a, b}`, which makes
+        /// every borrow in the group mutable.
+        pub mutability: Option<Token![mut]>,
         pub brace_token: token::Brace,
         pub borrows: Punctuated<PartialBorrow, Token![,]>,
     }
 }
 
+impl PartialBorrow {
+    /// A shared borrow of the field `ident`.
+    pub fn shared(ident: Ident) -> Self {
+        PartialBorrow {
+            mutability: None,
+            ident,
+        }
+    }
+
+    /// A mutable borrow of the field `ident`, with the `mut` token spanned
+    /// to match the ident.
+    pub fn mutable(ident: Ident) -> Self {
+        PartialBorrow {
+            mutability: Some(Token![mut](ident.span())),
+            ident,
+        }
+    }
+}
+
+impl FromIterator<PartialBorrow> for PartialBorrows {
+    /// Builds the group `{a, mut b}` with a call-site brace span.
+    fn from_iter<I: IntoIterator<Item = PartialBorrow>>(iter: I) -> Self {
+        PartialBorrows {
+            mutability: None,
+            brace_token: token::Brace::default(),
+            borrows: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl PartialBorrows {
+    /// Whether the whole group is borrowed mutably: `self.mut{a, b}`.
+    pub fn is_group_mut(&self) -> bool {
+        self.mutability.is_some()
+    }
+
+    /// Rewrites the group into a canonical form for deterministic output:
+    /// borrows are sorted by field name, and a field borrowed more than once
+    /// is kept once, mutably if any of its borrows is mutable.
+    ///
+    /// The borrowed idents and `mut` tokens keep their spans. A trailing
+    /// comma is kept if there was one.
+    pub fn canonicalize(&mut self) {
+        let trailing = self.borrows.trailing_punct();
+        let mut borrows: Vec<PartialBorrow> =
+            mem::replace(&mut self.borrows, Punctuated::new()).into_iter().collect();
+        borrows.sort_by(|a, b| a.ident.to_string().cmp(&b.ident.to_string()));
+
+        for borrow in borrows {
+            if let Some(last) = self.borrows.last_mut() {
+                if last.ident == borrow.ident {
+                    if last.mutability.is_none() {
+                        last.mutability = borrow.mutability;
+                    }
+                    continue;
+                }
+            }
+            self.borrows.push(borrow);
+        }
+        if trailing && !self.borrows.is_empty() {
+            self.borrows.push_punct(Default::default());
+        }
+    }
+
+    /// Checks that every borrowed field is one of the `known` fields of the
+    /// struct and that no field is borrowed twice.
+    ///
+    /// The error is spanned at the first offending ident.
+    pub fn validate_against(&self, known: &[Ident]) -> Result<()> {
+        for (i, borrow) in self.borrows.iter().enumerate() {
+            let ident = &borrow.ident;
+            if !known.contains(ident) {
+                let msg = format!("no field named `{}`", ident);
+                return Err(Error::new(ident.span(), msg));
+            }
+            if self.borrows.iter().take(i).any(|prev| prev.ident == *ident) {
+                let msg = format!("field `{}` is borrowed more than once", ident);
+                return Err(Error::new(ident.span(), msg));
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -39,6 +128,7 @@ pub mod parsing {
         fn parse(input: ParseStream) -> Result<Self> {
             let content;
             Ok(PartialBorrows {
+                mutability: input.parse()?,
                 brace_token: braced!(content in input),
                 borrows: content.parse_terminated(PartialBorrow::parse)?,
             })
@@ -62,6 +152,7 @@ pub mod printing {
 
     impl ToTokens for PartialBorrows {
         fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.mutability.to_tokens(tokens);
             self.brace_token.surround(tokens, |tokens| {
                 self.borrows.to_tokens(tokens);
             });
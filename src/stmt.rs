@@ -47,6 +47,7 @@ ast_struct! {
 pub mod parsing {
     use super::*;
 
+    use crate::item::kw;
     use crate::parse::{Parse, ParseStream, Result};
     use crate::punctuated::Punctuated;
 
@@ -169,13 +170,18 @@ pub mod parsing {
             || ahead.peek(Token![const])
             || ahead.peek(Token![unsafe]) && !ahead.peek2(token::Brace)
             || ahead.peek(Token![async])
+                && (ahead.peek2(kw::gen)
+                    || ahead.peek2(Token![unsafe])
+                    || ahead.peek2(Token![extern])
+                    || ahead.peek2(Token![fn]))
+            || ahead.peek(kw::gen)
                 && (ahead.peek2(Token![unsafe])
                     || ahead.peek2(Token![extern])
                     || ahead.peek2(Token![fn]))
             || ahead.peek(Token![fn])
             || ahead.peek(Token![mod])
             || ahead.peek(Token![type])
-            || ahead.peek(item::parsing::existential) && ahead.peek2(Token![type])
+            || ahead.peek(kw::existential) && ahead.peek2(Token![type])
             || ahead.peek(Token![struct])
             || ahead.peek(Token![enum])
             || ahead.peek(Token![union]) && ahead.peek2(Ident)
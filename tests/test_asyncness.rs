@@ -3,7 +3,8 @@ mod features;
 #[macro_use]
 mod macros;
 
-use syn::{Expr, Item};
+use quote::ToTokens;
+use syn::{Expr, Item, Stmt};
 
 #[test]
 fn test_async_fn() {
@@ -37,3 +38,41 @@ fn test_async_closure() {
     }
     "###);
 }
+
+#[test]
+fn test_await_chain() {
+    let input = "async fn f() { g().await.h().await }";
+    let item: Item = syn::parse_str(input).unwrap();
+    let reparsed: Item = syn::parse_str(&item.to_token_stream().to_string()).unwrap();
+    assert_eq!(item, reparsed);
+
+    let item = match item {
+        Item::Fn(item) => item,
+        value => panic!("expected Item::Fn, got {:?}", value),
+    };
+    let expr = match &item.block.stmts[..] {
+        [Stmt::Expr(expr)] => expr,
+        value => panic!("expected a single trailing expression, got {:?}", value),
+    };
+    snapshot!(expr, @r###"
+    Expr::Await {
+        base: Expr::MethodCall {
+            receiver: Expr::Await {
+                base: Expr::Call {
+                    func: Expr::Path {
+                        path: Path {
+                            segments: [
+                                PathSegment {
+                                    ident: "g",
+                                    arguments: None,
+                                },
+                            ],
+                        },
+                    },
+                },
+            },
+            method: "h",
+        },
+    }
+    "###);
+}
@@ -4,7 +4,7 @@ mod features;
 mod macros;
 
 use syn::parse::Parser;
-use syn::{Attribute, Meta};
+use syn::{Attribute, ItemFn, Meta};
 
 #[test]
 fn test_meta_item_word() {
@@ -286,6 +286,45 @@ fn test_bool_lit() {
     "###);
 }
 
+#[test]
+fn test_collect_doc() {
+    let attrs = Attribute::parse_outer
+        .parse_str(
+            r##"
+            /// First line.
+            #[derive(Debug)]
+            ///   Indented line.
+            #[doc(hidden)]
+            #[doc = r"Raw \n line."]
+            #[inline]
+            "##,
+        )
+        .unwrap();
+
+    assert_eq!(syn::collect_doc(&attrs), "First line.\n  Indented line.\nRaw \\n line.");
+}
+
+#[test]
+fn test_is_public_api() {
+    let item: ItemFn = syn::parse_str("/// Docs.\npub fn f() {}").unwrap();
+    assert!(syn::is_public_api(&item.attrs, &item.vis));
+
+    let item: ItemFn = syn::parse_str("#[doc(hidden)] pub fn f() {}").unwrap();
+    assert!(!syn::is_public_api(&item.attrs, &item.vis));
+
+    let item: ItemFn = syn::parse_str("#[doc(inline, hidden)] pub fn f() {}").unwrap();
+    assert!(!syn::is_public_api(&item.attrs, &item.vis));
+
+    let item: ItemFn = syn::parse_str("#[doc = \"hidden\"] pub fn f() {}").unwrap();
+    assert!(syn::is_public_api(&item.attrs, &item.vis));
+
+    let item: ItemFn = syn::parse_str("fn f() {}").unwrap();
+    assert!(!syn::is_public_api(&item.attrs, &item.vis));
+
+    let item: ItemFn = syn::parse_str("pub(crate) fn f() {}").unwrap();
+    assert!(!syn::is_public_api(&item.attrs, &item.vis));
+}
+
 fn test(input: &str) -> Meta {
     let attrs = Attribute::parse_outer.parse_str(input).unwrap();
 
@@ -0,0 +1,2028 @@
+mod features;
+
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use std::collections::HashMap;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_quote, Block, CachedItem, Expr, ExprUnary, FnArg, ForeignItem, ForeignItemFn,
+    GenericArgument, Generics, HasMacro, Ident, ImplItem, ImplItemMethod, ImplItemType, Item,
+    ItemEnum, ItemFn, ItemForeignMod, ItemImpl, ItemKind, ItemMacro, ItemMod, ItemStruct, ItemTrait,
+    ItemType, ItemUnion, ItemUse, Lifetime, PathArguments, Signature, Token, TraitBoundModifier,
+    TraitItem, TraitItemConst, TraitItemMethod, TraitItemType, Type, TypeParamBound, UnOp, UseTree,
+    UseTreeWithAttrs, Visibility, WherePredicate,
+};
+
+fn roundtrip(tokens: proc_macro2::TokenStream) -> Item {
+    let item: Item = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(item.to_token_stream().to_string(), tokens.to_string());
+    item
+}
+
+#[test]
+fn test_gen_fn() {
+    let item = roundtrip(quote!(gen fn f() {}));
+    match item {
+        Item::Fn(ItemFn { sig, .. }) => {
+            assert!(sig.asyncness.is_none());
+            assert!(sig.genness.is_some());
+        }
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_async_gen_fn() {
+    let item = roundtrip(quote!(async gen fn g() {}));
+    match item {
+        Item::Fn(ItemFn { sig, .. }) => {
+            assert!(sig.asyncness.is_some());
+            assert!(sig.genness.is_some());
+        }
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_plain_fn() {
+    let item = roundtrip(quote!(fn h() {}));
+    match item {
+        Item::Fn(ItemFn { sig, .. }) => {
+            assert!(sig.asyncness.is_none());
+            assert!(sig.genness.is_none());
+        }
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_gen_macro_is_not_fn() {
+    match syn::parse2(quote!(gen! {})).unwrap() {
+        Item::Macro(_) => (),
+        value => panic!("expected Item::Macro, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_existential_type() {
+    let item = roundtrip(quote!(existential type Iter: Iterator<Item = u8>;));
+    match item {
+        Item::Existential(item) => {
+            assert_eq!(item.ident, "Iter");
+            assert_eq!(item.bounds.len(), 1);
+        }
+        value => panic!("expected Item::Existential, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_existential_type_with_where_clause() {
+    let item = roundtrip(quote! {
+        pub existential type Iter<T> where T: Clone: Iterator<Item = T> + Send + 'static;
+    });
+    match item {
+        Item::Existential(item) => {
+            assert!(item.generics.where_clause.is_some());
+            assert_eq!(item.bounds.len(), 3);
+        }
+        value => panic!("expected Item::Existential, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_supertrait_sized() {
+    let item = roundtrip(quote!(trait T: Sized {}));
+    match item {
+        Item::Trait(item) => {
+            assert_eq!(item.supertraits.len(), 1);
+            assert!(item.generics.where_clause.is_none());
+        }
+        value => panic!("expected Item::Trait, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_where_self_sized() {
+    let item = roundtrip(quote!(trait T where Self: Sized {}));
+    match item {
+        Item::Trait(item) => {
+            assert!(item.colon_token.is_none());
+            assert!(item.supertraits.is_empty());
+            assert_eq!(item.generics.where_clause.unwrap().predicates.len(), 1);
+        }
+        value => panic!("expected Item::Trait, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_eq_ignoring_trailing_punct() {
+    let without: Item = syn::parse2(quote!(enum E { A, B })).unwrap();
+    let with: Item = syn::parse2(quote!(enum E { A, B, })).unwrap();
+    assert_ne!(without, with);
+    assert!(without.eq_ignoring_trailing_punct(&with));
+    assert!(with.eq_ignoring_trailing_punct(&without));
+
+    let different: Item = syn::parse2(quote!(enum E { A, C, })).unwrap();
+    assert!(!without.eq_ignoring_trailing_punct(&different));
+    assert_ne!(without, with);
+
+    let without: Item = parse_quote!(struct S<A, B>(A, B) where A: Copy;);
+    let with: Item = parse_quote!(struct S<A, B,>(A, B,) where A: Copy,;);
+    assert_ne!(without, with);
+    assert!(without.eq_ignoring_trailing_punct(&with));
+
+    let without: Item = parse_quote!(impl<T> S<T> where T: Copy { fn f(a: u8) {} });
+    let with: Item = parse_quote!(impl<T> S<T> where T: Copy, { fn f(a: u8,) {} });
+    assert!(without.eq_ignoring_trailing_punct(&with));
+
+    let tuple: Item = parse_quote!(type T = (u8,););
+    let paren: Item = parse_quote!(type T = (u8););
+    assert!(!tuple.eq_ignoring_trailing_punct(&paren));
+    assert!(!paren.eq_ignoring_trailing_punct(&tuple));
+
+    let tuple: Item = parse_quote!(fn f() { let (a,) = (1,); });
+    let paren: Item = parse_quote!(fn f() { let (a) = (1); });
+    assert!(!tuple.eq_ignoring_trailing_punct(&paren));
+
+    let without: Item = parse_quote!(fn f() { let S(a) = S(1, 2); });
+    let with: Item = parse_quote!(fn f() { let S(a,) = S(1, 2,); });
+    assert!(without.eq_ignoring_trailing_punct(&with));
+}
+
+#[test]
+fn test_non_exhaustive() {
+    let item: Item = syn::parse2(quote! {
+        #[derive(Debug)]
+        #[non_exhaustive]
+        pub enum E { A, B }
+    })
+    .unwrap();
+    assert!(item.is_non_exhaustive());
+
+    let item: Item = syn::parse2(quote! {
+        #[derive(Debug)]
+        pub enum E { A, B }
+    })
+    .unwrap();
+    assert!(!item.is_non_exhaustive());
+
+    let item: ItemStruct = syn::parse2(quote! {
+        #[non_exhaustive]
+        pub struct S { pub a: u8 }
+    })
+    .unwrap();
+    assert!(item.is_non_exhaustive());
+}
+
+#[test]
+fn test_use_group_member_attrs() {
+    let tokens = quote!(a::{#[cfg(x)] b, c::{#[cfg(y)] d, *}});
+    let tree = syn::parse::Parser::parse2(UseTree::parse_with_attrs, tokens.clone()).unwrap();
+    assert_eq!(tree.to_token_stream().to_string(), tokens.to_string());
+
+    let items = match &tree {
+        UseTreeWithAttrs::Path(ident, _, tree) => {
+            assert_eq!(ident, "a");
+            match &**tree {
+                UseTreeWithAttrs::Group(_, items) => items,
+                value => panic!("expected UseTreeWithAttrs::Group, got {:?}", value),
+            }
+        }
+        value => panic!("expected UseTreeWithAttrs::Path, got {:?}", value),
+    };
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].attrs.len(), 1);
+    assert!(items[0].attrs[0].path.is_ident("cfg"));
+    match &items[0].tree {
+        UseTreeWithAttrs::Tree(UseTree::Name(name)) => assert_eq!(name.ident, "b"),
+        value => panic!("expected UseTree::Name, got {:?}", value),
+    }
+    assert!(items[1].attrs.is_empty());
+
+    let plain = tree.into_use_tree();
+    assert_eq!(plain.to_token_stream().to_string(), quote!(a::{b, c::{d, *}}).to_string());
+}
+
+#[test]
+fn test_use_group_member_attrs_rejected_by_default() {
+    assert!(syn::parse2::<UseTree>(quote!(a::{#[cfg(x)] b, c})).is_err());
+    assert!(syn::parse2::<Item>(quote!(use a::{#[cfg(x)] b, c};)).is_err());
+}
+
+#[test]
+fn test_nested_items_in_fn_body() {
+    let item = roundtrip(quote! {
+        fn f() {
+            struct Local;
+            fn helper() {
+                enum Inner {}
+            }
+            impl Local {
+                fn method() {
+                    const C: u8 = 0;
+                }
+            }
+            helper();
+        }
+    });
+
+    let mut names = Vec::new();
+    item.for_each_nested_item(false, |item| names.push(item_name(item)));
+    assert!(names.is_empty());
+
+    item.for_each_nested_item(true, |item| names.push(item_name(item)));
+    assert_eq!(names, ["Local", "helper", "Inner", "impl", "C"]);
+}
+
+#[test]
+fn test_nested_items_in_mod() {
+    let item = roundtrip(quote! {
+        mod m {
+            struct S;
+            fn f() {
+                struct Local;
+            }
+        }
+    });
+
+    let mut names = Vec::new();
+    item.for_each_nested_item(false, |item| names.push(item_name(item)));
+    assert_eq!(names, ["S", "f"]);
+
+    names.clear();
+    item.for_each_nested_item(true, |item| names.push(item_name(item)));
+    assert_eq!(names, ["S", "f", "Local"]);
+}
+
+fn item_name(item: &Item) -> String {
+    match item {
+        Item::Const(item) => item.ident.to_string(),
+        Item::Enum(item) => item.ident.to_string(),
+        Item::Fn(item) => item.sig.ident.to_string(),
+        Item::Impl(_) => "impl".to_owned(),
+        Item::Struct(item) => item.ident.to_string(),
+        value => panic!("unexpected item {:?}", value),
+    }
+}
+
+#[test]
+fn test_struct_shape() {
+    let item: ItemStruct = syn::parse2(quote!(struct A { x: u8 })).unwrap();
+    assert!(item.is_named() && !item.is_tuple() && !item.is_unit());
+
+    let item: ItemStruct = syn::parse2(quote!(struct B(u8);)).unwrap();
+    assert!(!item.is_named() && item.is_tuple() && !item.is_unit());
+
+    let item: ItemStruct = syn::parse2(quote!(struct C;)).unwrap();
+    assert!(!item.is_named() && !item.is_tuple() && item.is_unit());
+}
+
+#[test]
+fn test_variant_shape() {
+    let item: ItemEnum = syn::parse2(quote!(enum E { A { x: u8 }, B(u8), C })).unwrap();
+    let variants: Vec<_> = item.variants.iter().collect();
+    assert!(variants[0].is_named() && !variants[0].is_tuple() && !variants[0].is_unit());
+    assert!(!variants[1].is_named() && variants[1].is_tuple() && !variants[1].is_unit());
+    assert!(!variants[2].is_named() && !variants[2].is_tuple() && variants[2].is_unit());
+}
+
+#[test]
+fn test_generic_param_counts() {
+    let item: ItemStruct = syn::parse2(quote! {
+        struct S<'a, T, const N: usize>(&'a [T; N]);
+    })
+    .unwrap();
+    assert_eq!(item.lifetime_count(), 1);
+    assert_eq!(item.type_param_count(), 1);
+    assert_eq!(item.const_param_count(), 1);
+
+    let item: ItemFn = syn::parse2(quote!(fn f<'a, 'b, T, U, V>() {})).unwrap();
+    assert_eq!(item.lifetime_count(), 2);
+    assert_eq!(item.type_param_count(), 3);
+    assert_eq!(item.const_param_count(), 0);
+}
+
+#[test]
+fn test_track_caller() {
+    let item = roundtrip(quote! {
+        #[inline]
+        #[track_caller]
+        pub fn f() {}
+    });
+    match item {
+        Item::Fn(item) => assert!(item.is_track_caller()),
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+
+    let item = roundtrip(quote! {
+        impl S {
+            #[track_caller]
+            fn tracked(&self) {}
+            fn untracked(&self) {}
+        }
+    });
+    match item {
+        Item::Impl(item) => {
+            let tracked: Vec<bool> = item
+                .items
+                .iter()
+                .map(|item| match item {
+                    ImplItem::Method(method) => method.is_track_caller(),
+                    value => panic!("expected ImplItem::Method, got {:?}", value),
+                })
+                .collect();
+            assert_eq!(tracked, [true, false]);
+        }
+        value => panic!("expected Item::Impl, got {:?}", value),
+    }
+
+    let item = roundtrip(quote! {
+        trait T {
+            #[track_caller]
+            fn tracked(&self);
+        }
+    });
+    match item {
+        Item::Trait(item) => match &item.items[0] {
+            TraitItem::Method(method) => assert!(method.is_track_caller()),
+            value => panic!("expected TraitItem::Method, got {:?}", value),
+        },
+        value => panic!("expected Item::Trait, got {:?}", value),
+    }
+
+    let item: ItemFn = syn::parse2(quote!(fn f() {})).unwrap();
+    assert!(!item.is_track_caller());
+}
+
+#[test]
+fn test_reexport_edges() {
+    let item: ItemMod = syn::parse2(quote! {
+        mod m {
+            pub use a::b as c;
+            pub use x::{y, z::{self, w}, glob::*, Trait as _};
+            use private::p;
+            pub(crate) use krate::k;
+        }
+    })
+    .unwrap();
+
+    let edges: Vec<(String, Vec<String>)> = item
+        .reexport_edges()
+        .into_iter()
+        .map(|(name, path)| {
+            let path = path.iter().map(|ident| ident.to_string()).collect();
+            (name.to_string(), path)
+        })
+        .collect();
+    let expected = [
+        ("c", vec!["a", "b"]),
+        ("y", vec!["x", "y"]),
+        ("z", vec!["x", "z"]),
+        ("w", vec!["x", "z", "w"]),
+    ];
+    let expected: Vec<(String, Vec<String>)> = expected
+        .iter()
+        .map(|(name, path)| {
+            let path = path.iter().map(|segment| segment.to_string()).collect();
+            (name.to_string(), path)
+        })
+        .collect();
+    assert_eq!(edges, expected);
+}
+
+#[test]
+fn test_impl_where_clause() {
+    let item = roundtrip(quote! {
+        impl<T: Send> Trait for Wrapper<T> where T: Sync + ?Sized {}
+    });
+    let item = match item {
+        Item::Impl(item) => item,
+        value => panic!("expected Item::Impl, got {:?}", value),
+    };
+    assert_eq!(item.generics.where_clause.unwrap().predicates.len(), 1);
+
+    roundtrip(quote! {
+        impl<F> Trait for Wrapper<F> where for<'a> F: Fn(&'a u8) -> &'a u8 {}
+    });
+    roundtrip(quote! {
+        impl<F> Trait for Wrapper<F> where F: for<'a> Fn(&'a u8) {}
+    });
+    roundtrip(quote! {
+        impl<'a, 'b, T> Trait for Wrapper<'a, 'b, T> where 'a: 'b, 'b: 'a + 'static, T: 'a {}
+    });
+}
+
+#[test]
+fn test_trait_predicates() {
+    let item: ItemTrait = syn::parse2(quote!(unsafe auto trait Send {})).unwrap();
+    assert!(item.is_auto());
+    assert!(item.is_unsafe());
+    assert!(item.is_marker_like());
+
+    let item: ItemTrait = syn::parse2(quote!(trait Marker {})).unwrap();
+    assert!(!item.is_auto());
+    assert!(!item.is_unsafe());
+    assert!(item.is_marker_like());
+
+    let item: ItemTrait = syn::parse2(quote!(trait Sub: Marker {})).unwrap();
+    assert!(!item.is_marker_like());
+
+    let item: ItemTrait = syn::parse2(quote! {
+        trait Normal {
+            fn method(&self);
+        }
+    })
+    .unwrap();
+    assert!(!item.is_auto());
+    assert!(!item.is_unsafe());
+    assert!(!item.is_marker_like());
+}
+
+#[test]
+fn test_sealed_trait() {
+    let item: ItemTrait = syn::parse2(quote!(pub trait T: private::Sealed {})).unwrap();
+    assert!(item.is_sealed());
+
+    let item: ItemTrait = syn::parse2(quote!(pub trait T: Clone + Sealed {})).unwrap();
+    assert!(item.is_sealed());
+
+    let item: ItemTrait = syn::parse2(quote!(pub trait T where Self: private::Sealed {})).unwrap();
+    assert!(item.is_sealed());
+
+    let item: ItemTrait = syn::parse2(quote!(pub trait T: Clone {})).unwrap();
+    assert!(!item.is_sealed());
+
+    let item: ItemTrait = syn::parse2(quote!(pub trait T<U> where U: Sealed {})).unwrap();
+    assert!(!item.is_sealed());
+}
+
+#[test]
+fn test_impl_empty_generics() {
+    let item: ItemImpl = syn::parse2(quote!(impl<> Foo {})).unwrap();
+    assert!(item.generics.lt_token.is_some());
+    assert!(item.generics.params.is_empty());
+    assert!(item.generics.gt_token.is_some());
+    assert_eq!(item.to_token_stream().to_string(), quote!(impl Foo {}).to_string());
+
+    let item: ItemImpl = syn::parse2(quote!(impl<> Trait for Foo {})).unwrap();
+    assert!(item.trait_.is_some());
+    assert_eq!(item.to_token_stream().to_string(), quote!(impl Trait for Foo {}).to_string());
+}
+
+#[test]
+fn test_enum_variant_lookup() {
+    let item: ItemEnum = syn::parse2(quote!(enum E { A, B(u8), C { x: u8 } })).unwrap();
+    assert!(item.variant("A").unwrap().is_unit());
+    assert!(item.variant("B").unwrap().is_tuple());
+    assert!(item.variant("C").unwrap().is_named());
+    assert!(item.variant("D").is_none());
+
+    let idents: Vec<_> = item.variant_idents().collect();
+    assert_eq!(idents, ["A", "B", "C"]);
+}
+
+#[test]
+fn test_assoc_const_types() {
+    let item: ItemTrait = syn::parse2(quote! {
+        trait T {
+            const A: u8;
+            fn f();
+            const B: &'static str = "b";
+        }
+    })
+    .unwrap();
+    let consts: Vec<(String, String)> = item
+        .assoc_const_types()
+        .into_iter()
+        .map(|(ident, ty)| (ident.to_string(), ty.to_token_stream().to_string()))
+        .collect();
+    let expected = [
+        ("A".to_owned(), quote!(u8).to_string()),
+        ("B".to_owned(), quote!(&'static str).to_string()),
+    ];
+    assert_eq!(consts, expected);
+}
+
+#[test]
+fn test_const_param_default_on_type_alias() {
+    let item = roundtrip(quote!(type Alias<const N: usize = 4> = [u8; N];));
+    let item = match item {
+        Item::Type(item) => item,
+        value => panic!("expected Item::Type, got {:?}", value),
+    };
+    let param = item.generics.const_params().next().unwrap();
+    assert!(param.default.is_some());
+
+    let item = roundtrip(quote! {
+        trait T {
+            type Item<const N: usize = 4>;
+        }
+    });
+    match item {
+        Item::Trait(item) => match &item.items[0] {
+            TraitItem::Type(item) => {
+                let param = item.generics.const_params().next().unwrap();
+                assert!(param.default.is_some());
+            }
+            value => panic!("expected TraitItem::Type, got {:?}", value),
+        },
+        value => panic!("expected Item::Trait, got {:?}", value),
+    }
+
+    let item = roundtrip(quote! {
+        impl T for S {
+            type Item<const N: usize = 4> = [u8; N];
+        }
+    });
+    match item {
+        Item::Impl(item) => match &item.items[0] {
+            ImplItem::Type(item) => {
+                let param = item.generics.const_params().next().unwrap();
+                assert!(param.default.is_some());
+            }
+            value => panic!("expected ImplItem::Type, got {:?}", value),
+        },
+        value => panic!("expected Item::Impl, got {:?}", value),
+    }
+
+    roundtrip(quote!(struct S<const N: usize = { 2 + 2 }, const M: usize = N>;));
+
+    let item = roundtrip(quote!(struct S<const N: i32 = -1>;));
+    let item = match item {
+        Item::Struct(item) => item,
+        value => panic!("expected Item::Struct, got {:?}", value),
+    };
+    let param = item.generics.const_params().next().unwrap();
+    match &param.default {
+        Some(Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. })) => match **expr {
+            Expr::Lit(_) => {}
+            ref value => panic!("expected Expr::Lit, got {:?}", value),
+        },
+        value => panic!("expected negated literal, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_trait_alias_where_clause() {
+    let item = roundtrip(quote!(trait Foo<T> = Bar + Baz where T: Clone, T: Send,;));
+    let reparsed: Item = syn::parse2(item.to_token_stream()).unwrap();
+    assert_eq!(item, reparsed);
+    match item {
+        Item::TraitAlias(item) => {
+            assert_eq!(item.bounds.len(), 2);
+            let where_clause = item.generics.where_clause.unwrap();
+            assert_eq!(where_clause.predicates.len(), 2);
+            assert!(where_clause.predicates.trailing_punct());
+        }
+        value => panic!("expected Item::TraitAlias, got {:?}", value),
+    }
+
+    roundtrip(quote!(trait Foo<T> = Bar + Baz where T: Clone, T: Send;));
+}
+
+#[test]
+fn test_phantom_fields() {
+    let item: ItemStruct = syn::parse2(quote! {
+        struct S<T, U> {
+            value: U,
+            marker: PhantomData<T>,
+            other: std::marker::PhantomData<fn() -> U>,
+        }
+    })
+    .unwrap();
+    let names: Vec<_> = item
+        .phantom_fields()
+        .into_iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect();
+    assert_eq!(names, ["marker", "other"]);
+
+    let item: ItemStruct = syn::parse2(quote!(struct S(u8);)).unwrap();
+    assert!(item.phantom_fields().is_empty());
+}
+
+#[test]
+fn test_missing_semi_messages() {
+    fn error(input: &str) -> String {
+        syn::parse_str::<Item>(input).unwrap_err().to_string()
+    }
+
+    assert_eq!(error("struct Foo(u8)"), "expected `;` after tuple struct definition");
+    assert_eq!(error("struct Foo"), "expected `;` after unit struct definition");
+    assert_eq!(error("struct Foo<T> where T: Copy"), "expected `;` after unit struct definition");
+    assert_eq!(error("type Alias = u8"), "expected `;` after type alias");
+    assert_eq!(error("static S: u8 = 0"), "expected `;` after static item");
+    assert_eq!(error("const C: u8 = 0"), "expected `;` after const item");
+    assert_eq!(error("trait Alias = Send + Sync"), "expected `;` after trait alias");
+
+    let file = syn::parse_str::<syn::File>("struct Foo(u8) fn f() {}");
+    assert_eq!(file.unwrap_err().to_string(), "expected `;` after tuple struct definition");
+
+    // The error points at the last token of the item, not at whatever follows.
+    #[cfg(feature = "span-locations")]
+    {
+        for (input, column) in &[
+            ("struct Foo(u8) fn f() {}", 13),
+            ("struct Foo fn f() {}", 7),
+            ("type Alias = Vec<u8> fn f() {}", 19),
+            ("trait Alias = Send fn f() {}", 14),
+        ] {
+            let err = syn::parse_str::<syn::File>(input).unwrap_err();
+            let start = err.span().start();
+            assert_eq!((start.line, start.column), (1, *column), "{}", input);
+        }
+    }
+}
+
+#[test]
+fn test_make_pub() {
+    let mut item: Item = syn::parse2(quote!(fn f() {})).unwrap();
+    item.make_pub();
+    assert_eq!(item.to_token_stream().to_string(), quote!(pub fn f() {}).to_string());
+
+    let mut item: Item = syn::parse2(quote!(pub(crate) struct S(u8);)).unwrap();
+    item.make_pub();
+    assert_eq!(item.to_token_stream().to_string(), quote!(pub struct S(u8);).to_string());
+
+    let mut item: Item = syn::parse2(quote!(impl S {})).unwrap();
+    assert!(item.vis_mut().is_none());
+    item.make_pub();
+    assert_eq!(item.to_token_stream().to_string(), quote!(impl S {}).to_string());
+}
+
+#[test]
+fn test_restricted_visibility() {
+    for vis in &[
+        quote!(pub(crate)),
+        quote!(pub(super)),
+        quote!(pub(self)),
+        quote!(pub(in a::b::c)),
+        quote!(pub(in crate::foo)),
+    ] {
+        roundtrip(quote!(#vis fn f() {}));
+        roundtrip(quote!(#vis struct S(#vis u8);));
+        roundtrip(quote! {
+            impl S {
+                #vis const C: u8 = 0;
+            }
+        });
+    }
+}
+
+#[test]
+fn test_use_classification() {
+    let item: ItemUse = syn::parse2(quote!(use a::*;)).unwrap();
+    assert!(item.is_glob());
+    assert!(item.bound_idents().is_empty());
+
+    let item: ItemUse = syn::parse2(quote!(use a::{b, c::*};)).unwrap();
+    assert!(item.is_glob());
+    assert_eq!(item.bound_idents(), ["b"]);
+
+    let item: ItemUse = syn::parse2(quote!(use a::b as c;)).unwrap();
+    assert!(!item.is_glob());
+    assert_eq!(item.bound_idents(), ["c"]);
+
+    let item: ItemUse = syn::parse2(quote!(use a::{self, b::{c, d as e}, f as _};)).unwrap();
+    assert!(!item.is_glob());
+    assert_eq!(item.bound_idents(), ["a", "c", "e"]);
+}
+
+#[test]
+fn test_trait_method_into_impl_method() {
+    let method: TraitItemMethod = syn::parse2(quote! {
+        #[inline]
+        fn f(&self) -> u8;
+    })
+    .unwrap();
+    let block: Block = syn::parse2(quote!({ 0 })).unwrap();
+    let vis: Visibility = syn::parse2(quote!(pub)).unwrap();
+    let err = method.clone().into_impl_method_with_default_body(vis.clone());
+    assert_eq!(err.unwrap_err().to_string(), "trait method has no default body");
+    let method = method.into_impl_method(block, vis);
+    let expected = quote! {
+        #[inline]
+        pub fn f(&self) -> u8 { 0 }
+    };
+    assert_eq!(method.to_token_stream().to_string(), expected.to_string());
+
+    let method: TraitItemMethod = syn::parse2(quote! {
+        fn g(&self) -> u8 { 1 }
+    })
+    .unwrap();
+    let method = method.into_impl_method_with_default_body(Visibility::Inherited).unwrap();
+    assert!(method.defaultness.is_none());
+    let expected = quote!(fn g(&self) -> u8 { 1 });
+    assert_eq!(method.to_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_abi_name() {
+    let item: ItemFn = syn::parse2(quote!(extern "C" fn f() {})).unwrap();
+    assert_eq!(item.sig.abi_name(), Some("C".to_owned()));
+
+    let item: ItemFn = syn::parse2(quote!(extern "system" fn f() {})).unwrap();
+    assert_eq!(item.sig.abi_name(), Some("system".to_owned()));
+
+    let item: ItemFn = syn::parse2(quote!(extern fn f() {})).unwrap();
+    assert_eq!(item.sig.abi_name(), Some("C".to_owned()));
+
+    let item: ItemFn = syn::parse2(quote!(fn f() {})).unwrap();
+    assert_eq!(item.sig.abi_name(), None);
+}
+
+#[test]
+fn test_unwind_abi() {
+    let item = roundtrip(quote!(extern "C-unwind" fn f() {}));
+    match item {
+        Item::Fn(item) => {
+            assert_eq!(item.sig.abi_name(), Some("C-unwind".to_owned()));
+            assert!(item.sig.is_unwind_abi());
+        }
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(extern "system" fn f() {}));
+    match item {
+        Item::Fn(item) => assert!(!item.sig.is_unwind_abi()),
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(extern "stdcall" fn f() {}));
+    match item {
+        Item::Fn(item) => assert!(!item.sig.is_unwind_abi()),
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(fn f() {}));
+    match item {
+        Item::Fn(item) => assert!(!item.sig.is_unwind_abi()),
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_assoc_item_sig() {
+    let item: ItemImpl = syn::parse2(quote! {
+        impl S {
+            const C: u8 = 0;
+            fn a(&self) {}
+            type T = u8;
+            fn b() {}
+            m!();
+        }
+    })
+    .unwrap();
+    let methods: Vec<_> = item
+        .items
+        .iter()
+        .filter_map(ImplItem::sig)
+        .map(|sig| &sig.ident)
+        .collect();
+    assert_eq!(methods, ["a", "b"]);
+
+    let item: ItemTrait = syn::parse2(quote! {
+        trait T {
+            const C: u8;
+            fn a(&self);
+            type T;
+            fn b() {}
+        }
+    })
+    .unwrap();
+    let methods: Vec<_> = item
+        .items
+        .iter()
+        .filter_map(TraitItem::sig)
+        .map(|sig| &sig.ident)
+        .collect();
+    assert_eq!(methods, ["a", "b"]);
+}
+
+#[test]
+fn test_item_kind() {
+    let items = vec![
+        (quote!(const C: u8 = 0;), ItemKind::Const),
+        (quote!(enum E {}), ItemKind::Enum),
+        (quote!(existential type E: Send;), ItemKind::Existential),
+        (quote!(extern crate c;), ItemKind::ExternCrate),
+        (quote!(fn f() {}), ItemKind::Fn),
+        (quote!(extern "C" {}), ItemKind::ForeignMod),
+        (quote!(impl S {}), ItemKind::Impl),
+        (quote!(m!();), ItemKind::Macro),
+        (quote!(macro m() {}), ItemKind::Macro2),
+        (quote!(mod m {}), ItemKind::Mod),
+        (quote!(static S: u8 = 0;), ItemKind::Static),
+        (quote!(struct S;), ItemKind::Struct),
+        (quote!(trait T {}), ItemKind::Trait),
+        (quote!(trait T = Send;), ItemKind::TraitAlias),
+        (quote!(type T = u8;), ItemKind::Type),
+        (quote!(union U { x: u8 }), ItemKind::Union),
+        (quote!(use a::b;), ItemKind::Use),
+    ];
+
+    let mut buckets = HashMap::new();
+    for (tokens, kind) in items {
+        let item: Item = syn::parse2(tokens).unwrap();
+        assert_eq!(item.kind(), kind);
+        buckets.entry(item.kind()).or_insert_with(Vec::new).push(item);
+    }
+    assert_eq!(buckets.len(), 17);
+
+    let item = Item::Verbatim(quote!(unknown item));
+    assert_eq!(item.kind(), ItemKind::Verbatim);
+}
+
+#[test]
+fn test_higher_ranked_bounds() {
+    let item = roundtrip(quote!(trait A = for<'a> Fn(&'a u8) + Send;));
+    match item {
+        Item::TraitAlias(item) => match &item.bounds[0] {
+            TypeParamBound::Trait(bound) => assert!(bound.lifetimes.is_some()),
+            value => panic!("expected TypeParamBound::Trait, got {:?}", value),
+        },
+        value => panic!("expected Item::TraitAlias, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(trait A: for<'a> Fn(&'a u8) + Send {}));
+    match item {
+        Item::Trait(item) => match &item.supertraits[0] {
+            TypeParamBound::Trait(bound) => assert!(bound.lifetimes.is_some()),
+            value => panic!("expected TypeParamBound::Trait, got {:?}", value),
+        },
+        value => panic!("expected Item::Trait, got {:?}", value),
+    }
+
+    roundtrip(quote!(trait A = Send + for<'a, 'b> Fn(&'a u8, &'b u8) -> &'a u8;));
+    roundtrip(quote!(trait A: Send + for<'a> PartialEq<&'a Self> {}));
+}
+
+#[test]
+fn test_add_lifetime() {
+    let mut item: ItemFn = syn::parse2(quote!(fn f(x: &u8) {})).unwrap();
+    item.sig.add_lifetime(Lifetime::new("'a", Span::call_site()));
+    assert_eq!(item.to_token_stream().to_string(), quote!(fn f<'a>(x: &u8) {}).to_string());
+
+    let mut item: ItemFn = syn::parse2(quote!(fn f<'b, T>(x: &'b T) {})).unwrap();
+    item.sig.add_lifetime(Lifetime::new("'a", Span::call_site()));
+    let expected = quote!(fn f<'a, 'b, T>(x: &'b T) {});
+    assert_eq!(item.to_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_macro2_params_and_body() {
+    let item = roundtrip(quote!(macro m { () => {} }));
+    match item {
+        Item::Macro2(item) => {
+            assert!(item.params().is_none());
+            let body = item.body().unwrap();
+            assert_eq!(body.stream().to_string(), quote!(() => {}).to_string());
+            assert_eq!(item.raw().to_string(), quote!({ () => {} }).to_string());
+        }
+        value => panic!("expected Item::Macro2, got {:?}", value),
+    }
+
+    let item: Item = syn::parse_str("macro m($x:expr) { $x }").unwrap();
+    match item {
+        Item::Macro2(item) => {
+            let params = item.params().unwrap();
+            assert_eq!(params.stream().to_string(), quote!($x:expr).to_string());
+            #[cfg(feature = "span-locations")]
+            assert_eq!(params.span().start().column, 7);
+            let body = item.body().unwrap();
+            assert_eq!(body.stream().to_string(), quote!($x).to_string());
+            #[cfg(feature = "span-locations")]
+            assert_eq!(body.span().start().column, 17);
+        }
+        value => panic!("expected Item::Macro2, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_macro2_param_fragments() {
+    fn fragments(src: &str) -> Vec<(String, String)> {
+        match syn::parse_str(src).unwrap() {
+            Item::Macro2(item) => item
+                .param_fragments()
+                .into_iter()
+                .map(|(name, kind)| (name.to_string(), kind.to_string()))
+                .collect(),
+            value => panic!("expected Item::Macro2, got {:?}", value),
+        }
+    }
+
+    assert_eq!(fragments("macro m($x:expr) { $x }"), [("x".to_owned(), "expr".to_owned())]);
+    assert_eq!(
+        fragments("macro m($a:ident, $($b:ty),* ; $c:tt) {}"),
+        [
+            ("a".to_owned(), "ident".to_owned()),
+            ("b".to_owned(), "ty".to_owned()),
+            ("c".to_owned(), "tt".to_owned()),
+        ]
+    );
+    assert!(fragments("macro m() {}").is_empty());
+    assert!(fragments("macro m { ($x:expr) => { $x } }").is_empty());
+}
+
+#[test]
+fn test_filtered_items() {
+    let item: ItemImpl = syn::parse2(quote! {
+        impl Trait for S {
+            const C: u8 = 0;
+            fn f() {}
+            type T = u8;
+            m!();
+        }
+    })
+    .unwrap();
+    assert_eq!(item.associated_consts().count(), 1);
+    assert_eq!(item.methods().count(), 1);
+    assert_eq!(item.associated_types().count(), 1);
+    assert_eq!(item.methods().next().unwrap().sig.ident, "f");
+
+    let item: ItemTrait = syn::parse2(quote! {
+        trait Trait {
+            const C: u8;
+            fn f();
+            type T;
+            m!();
+        }
+    })
+    .unwrap();
+    assert_eq!(item.associated_consts().count(), 1);
+    assert_eq!(item.methods().count(), 1);
+    assert_eq!(item.associated_types().count(), 1);
+    assert_eq!(item.associated_types().next().unwrap().ident, "T");
+}
+
+#[test]
+fn test_signature_default() {
+    let mut sig = Signature::default();
+    sig.ident = Ident::new("f", Span::call_site());
+    sig.inputs.push(parse_quote!(x: u8));
+    assert_eq!(sig.to_token_stream().to_string(), quote!(fn f(x: u8)).to_string());
+}
+
+#[test]
+fn test_has_cfg() {
+    let item: Item = parse_quote! {
+        #[cfg(feature = "x")]
+        fn f() {}
+    };
+    assert!(item.has_cfg());
+
+    let item: Item = parse_quote! {
+        #[cfg_attr(feature = "x", derive(Clone))]
+        struct S;
+    };
+    assert!(!item.has_cfg());
+
+    let item: Item = parse_quote!(mod m {});
+    assert!(!item.has_cfg());
+}
+
+#[test]
+fn test_is_gat() {
+    let item: TraitItemType = parse_quote!(type Item;);
+    assert!(!item.is_gat());
+    let item: TraitItemType = parse_quote!(type Item<'a>;);
+    assert!(item.is_gat());
+    let item: TraitItemType = parse_quote!(type Item<T>;);
+    assert!(item.is_gat());
+    let item: TraitItemType = parse_quote!(type Item<const N: usize>;);
+    assert!(item.is_gat());
+
+    let item: ImplItemType = parse_quote!(type Item = u8;);
+    assert!(!item.is_gat());
+    let item: ImplItemType = parse_quote!(type Item<'a> = &'a u8;);
+    assert!(item.is_gat());
+}
+
+#[test]
+fn test_macro_delimiter() {
+    let item: ItemMacro = parse_quote!(m!(););
+    let delimiter = &item.mac.delimiter;
+    assert!(delimiter.is_paren() && !delimiter.is_brace() && !delimiter.is_bracket());
+
+    let item: ItemMacro = parse_quote!(m! {});
+    let delimiter = &item.mac.delimiter;
+    assert!(!delimiter.is_paren() && delimiter.is_brace() && !delimiter.is_bracket());
+    assert!(item.semi_token.is_none());
+
+    let item: ItemMacro = parse_quote!(m![];);
+    let delimiter = &item.mac.delimiter;
+    assert!(!delimiter.is_paren() && !delimiter.is_brace() && delimiter.is_bracket());
+}
+
+#[test]
+fn test_extern_crate_self() {
+    let item = roundtrip(quote!(extern crate self as foo;));
+    match item {
+        Item::ExternCrate(item) => {
+            assert!(item.is_self());
+            assert_eq!(item.rename.unwrap().1, "foo");
+        }
+        value => panic!("expected Item::ExternCrate, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(extern crate serde;));
+    match item {
+        Item::ExternCrate(item) => assert!(!item.is_self() && item.rename.is_none()),
+        value => panic!("expected Item::ExternCrate, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(extern crate serde as s;));
+    match item {
+        Item::ExternCrate(item) => assert!(!item.is_self() && item.rename.is_some()),
+        value => panic!("expected Item::ExternCrate, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_macro_trailing_semi() {
+    let item = roundtrip(quote!(m! {};));
+    match item {
+        Item::Macro(item) => assert!(item.semi_token.is_some()),
+        value => panic!("expected Item::Macro, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(m! {}));
+    match item {
+        Item::Macro(item) => assert!(item.semi_token.is_none()),
+        value => panic!("expected Item::Macro, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(m!(x);));
+    match item {
+        Item::Macro(item) => assert!(item.semi_token.is_some()),
+        value => panic!("expected Item::Macro, got {:?}", value),
+    }
+
+    let file: syn::File = parse_quote! {
+        m! {};
+        fn f() {}
+    };
+    assert_eq!(file.items.len(), 2);
+}
+
+#[test]
+fn test_field_types() {
+    fn strings<'a>(types: impl Iterator<Item = &'a Type>) -> Vec<String> {
+        types.map(|ty| ty.to_token_stream().to_string()).collect()
+    }
+
+    let item: ItemStruct = parse_quote!(struct S { a: u8, b: Vec<T> });
+    assert_eq!(strings(item.field_types()), ["u8", "Vec < T >"]);
+
+    let item: ItemStruct = parse_quote!(struct S(u8, String););
+    assert_eq!(strings(item.field_types()), ["u8", "String"]);
+
+    let item: ItemUnion = parse_quote!(union U { a: u8, b: f32 });
+    assert_eq!(strings(item.field_types()), ["u8", "f32"]);
+
+    let item: ItemEnum = parse_quote! {
+        enum E {
+            Unit,
+            Tuple(u8, u16),
+            Struct { a: u32 },
+        }
+    };
+    assert_eq!(strings(item.field_types()), ["u8", "u16", "u32"]);
+}
+
+#[test]
+fn test_parse_bare_items() {
+    let tokens = quote! {
+        fn f(&self) {}
+        const C: u8 = 0;
+        fn g() {}
+    };
+    let items = syn::parse_impl_items.parse2(tokens.clone()).unwrap();
+    assert_eq!(items.len(), 3);
+    match &items[1] {
+        ImplItem::Const(item) => assert_eq!(item.ident, "C"),
+        value => panic!("expected ImplItem::Const, got {:?}", value),
+    }
+    let items = syn::parse_trait_items.parse2(tokens).unwrap();
+    assert_eq!(items.len(), 3);
+
+    let items = syn::parse_foreign_items
+        .parse2(quote! {
+            fn f();
+            static S: u8;
+        })
+        .unwrap();
+    assert_eq!(items.len(), 2);
+
+    assert!(syn::parse_impl_items.parse2(quote!()).unwrap().is_empty());
+}
+
+#[test]
+fn test_union_contextual_keyword() {
+    let item = roundtrip(quote!(pub union Foo<A, B> { a: A, b: B }));
+    match item {
+        Item::Union(item) => assert_eq!(item.ident, "Foo"),
+        value => panic!("expected Item::Union, got {:?}", value),
+    }
+
+    roundtrip(quote!(union! {}));
+
+    for misuse in &["union();", "union + 1;", "pub union();"] {
+        let err = syn::parse_str::<Item>(misuse).unwrap_err();
+        assert!(err.to_string().starts_with("expected a union name after `union`"));
+    }
+
+    let item: ItemFn = parse_quote!(fn f() { union(); union + 1; });
+    assert_eq!(item.block.stmts.len(), 2);
+
+    let err = syn::parse_str::<Item>("1;").unwrap_err().to_string();
+    assert_eq!(err.matches("`union`").count(), 1, "{}", err);
+}
+
+#[test]
+fn test_signature_to_string() {
+    let item: ItemFn = parse_quote!(fn f<T: Clone>(x: T) -> T where T: Debug { x });
+    let expected = quote!(fn f<T: Clone>(x: T) -> T where T: Debug);
+    assert_eq!(item.sig.to_string(), expected.to_string());
+
+    let item: ForeignItemFn = parse_quote!(fn printf(format: *const c_char, ...););
+    let expected = quote!(fn printf(format: *const c_char, ...));
+    assert_eq!(item.sig.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_relaxed_bounds() {
+    fn is_maybe(bound: &TypeParamBound) -> bool {
+        match bound {
+            TypeParamBound::Trait(bound) => match bound.modifier {
+                TraitBoundModifier::Maybe(_) => true,
+                TraitBoundModifier::None => false,
+            },
+            TypeParamBound::Lifetime(_) => false,
+        }
+    }
+
+    fn where_bound(generics: &Generics) -> &TypeParamBound {
+        match &generics.where_clause.as_ref().unwrap().predicates[0] {
+            WherePredicate::Type(predicate) => &predicate.bounds[0],
+            value => panic!("expected WherePredicate::Type, got {:?}", value),
+        }
+    }
+
+    let item = roundtrip(quote!(struct Wrapper<T: ?Sized>(Box<T>);));
+    match item {
+        Item::Struct(item) => {
+            let param = item.generics.type_params().next().unwrap();
+            assert!(is_maybe(&param.bounds[0]));
+        }
+        value => panic!("expected Item::Struct, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(struct Wrapper<T>(Box<T>) where T: ?Sized;));
+    match item {
+        Item::Struct(item) => assert!(is_maybe(where_bound(&item.generics))),
+        value => panic!("expected Item::Struct, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(fn f<T: ?Sized>(x: &T) {}));
+    match item {
+        Item::Fn(item) => {
+            let param = item.sig.generics.type_params().next().unwrap();
+            assert!(is_maybe(&param.bounds[0]));
+        }
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(fn f<T>(x: &T) where T: ?Sized {}));
+    match item {
+        Item::Fn(item) => assert!(is_maybe(where_bound(&item.sig.generics))),
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(impl<T: ?Sized> Trait for Box<T> {}));
+    match item {
+        Item::Impl(item) => {
+            let param = item.generics.type_params().next().unwrap();
+            assert!(is_maybe(&param.bounds[0]));
+        }
+        value => panic!("expected Item::Impl, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(impl<T> Trait for Box<T> where T: ?Sized + Debug {}));
+    match item {
+        Item::Impl(item) => assert!(is_maybe(where_bound(&item.generics))),
+        value => panic!("expected Item::Impl, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_use_tree_normalize() {
+    fn normalize(item: ItemUse) -> String {
+        item.tree.normalize().into_token_stream().to_string()
+    }
+
+    let item: ItemUse = parse_quote!(use a::{b};);
+    assert_eq!(normalize(item), quote!(a::b).to_string());
+
+    let item: ItemUse = parse_quote!(use a::{c, b};);
+    assert_eq!(normalize(item), quote!(a::{b, c}).to_string());
+
+    let item: ItemUse = parse_quote!(use a::{b::{c}};);
+    assert_eq!(normalize(item), quote!(a::b::c).to_string());
+
+    let item: ItemUse = parse_quote!(use a::{{x}, *, z as y, self, c::{e, d}};);
+    assert_eq!(normalize(item), quote!(a::{self, c::{d, e}, x, z as y, *}).to_string());
+
+    let item: ItemUse = parse_quote!(use a::{self};);
+    assert_eq!(normalize(item), quote!(a::{self}).to_string());
+
+    let item: ItemUse = parse_quote!(use a::{b as c};);
+    assert_eq!(normalize(item), quote!(a::b as c).to_string());
+
+    let item: ItemUse = parse_quote!(use a::{*};);
+    assert_eq!(normalize(item), quote!(a::*).to_string());
+}
+
+#[test]
+fn test_fn_stmts() {
+    let mut item: ItemFn = parse_quote! {
+        fn f() {
+            let x = 1;
+            g(x);
+            x
+        }
+    };
+    assert_eq!(item.stmts().len(), 3);
+    item.stmts_mut().insert(2, parse_quote!(h();));
+    let expected = quote! {
+        fn f() {
+            let x = 1;
+            g(x);
+            h();
+            x
+        }
+    };
+    assert_eq!(item.to_token_stream().to_string(), expected.to_string());
+
+    let mut method: ImplItemMethod = parse_quote!(fn f(&self) { a(); });
+    assert_eq!(method.stmts().len(), 1);
+    method.stmts_mut().push(parse_quote!(b();));
+    let expected = quote!(fn f(&self) { a(); b(); });
+    assert_eq!(method.to_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_export_name() {
+    let item: ItemFn = parse_quote! {
+        #[no_mangle]
+        pub extern "C" fn exported() {}
+    };
+    assert_eq!(item.export_name().as_ref().map(String::as_str), Some("exported"));
+
+    let item: ItemFn = parse_quote! {
+        #[export_name = "foo"]
+        pub extern "C" fn renamed() {}
+    };
+    assert_eq!(item.export_name().as_ref().map(String::as_str), Some("foo"));
+
+    let item: ItemFn = parse_quote!(pub fn plain() {});
+    assert_eq!(item.export_name(), None);
+}
+
+#[test]
+fn test_foreign_fn_validate() {
+    let item: ForeignItemFn = parse_quote!(fn printf(format: *const c_char, ...););
+    assert!(item.validate().is_ok());
+
+    let mut item: ForeignItemFn = parse_quote!(fn f(););
+    item.sig.constness = Some(Default::default());
+    let err = item.validate().unwrap_err();
+    assert_eq!(err.to_string(), "foreign functions cannot be `const`");
+
+    let mut item: ForeignItemFn = parse_quote!(fn f(););
+    item.sig.asyncness = Some(Default::default());
+    let err = item.validate().unwrap_err();
+    assert_eq!(err.to_string(), "foreign functions cannot be `async`");
+}
+
+#[test]
+fn test_is_diverging() {
+    let item: ItemFn = parse_quote!(fn f() -> ! { loop {} });
+    assert!(item.sig.is_diverging());
+
+    let item: ItemFn = parse_quote!(fn g() {});
+    assert!(!item.sig.is_diverging());
+
+    let item: ItemFn = parse_quote!(fn h() -> Infallible { unimplemented!() });
+    assert!(!item.sig.is_diverging());
+}
+
+#[test]
+fn test_items_recursive() {
+    let item: ItemMod = parse_quote! {
+        mod a {
+            fn x() {}
+            mod b {
+                fn y() {}
+            }
+            mod c;
+            fn z() {}
+        }
+    };
+    let names: Vec<String> = item
+        .items_recursive()
+        .map(|item| match item {
+            Item::Fn(item) => item.sig.ident.to_string(),
+            Item::Mod(item) => item.ident.to_string(),
+            value => panic!("unexpected item {:?}", value),
+        })
+        .collect();
+    assert_eq!(names, ["x", "b", "y", "c", "z"]);
+
+    let item: ItemMod = parse_quote!(mod m;);
+    assert_eq!(item.items_recursive().count(), 0);
+}
+
+#[test]
+fn test_impl_inner_outer_attrs() {
+    let item: ItemImpl = parse_quote! {
+        #[automatically_derived]
+        impl Trait for S {
+            #![allow(dead_code)]
+            fn f() {}
+        }
+    };
+    let outer: Vec<_> = item.outer_attrs().collect();
+    assert_eq!(outer.len(), 1);
+    assert!(outer[0].path.is_ident("automatically_derived"));
+    let inner: Vec<_> = item.inner_attrs().collect();
+    assert_eq!(inner.len(), 1);
+    assert!(inner[0].path.is_ident("allow"));
+    assert_eq!(item.attrs.len(), 2);
+}
+
+#[test]
+fn test_set_ident() {
+    let mut item: Item = parse_quote!(struct S { a: u8 });
+    assert!(item.set_ident(Ident::new("T", Span::call_site())));
+    assert_eq!(item.to_token_stream().to_string(), quote!(struct T { a: u8 }).to_string());
+
+    let mut item: Item = parse_quote!(fn f() {});
+    assert!(item.set_ident(Ident::new("g", Span::call_site())));
+    assert_eq!(item.to_token_stream().to_string(), quote!(fn g() {}).to_string());
+
+    let mut item: Item = parse_quote!(impl S {});
+    assert!(!item.set_ident(Ident::new("T", Span::call_site())));
+    assert_eq!(item.to_token_stream().to_string(), quote!(impl S {}).to_string());
+
+    let mut item: Item = parse_quote!(m! {});
+    assert!(!item.set_ident(Ident::new("n", Span::call_site())));
+}
+
+#[test]
+fn test_async_detection() {
+    let method: TraitItemMethod = parse_quote!(async fn f(&self););
+    assert!(method.sig.is_async());
+    assert!(!syn::has_async_trait_attr(&method.attrs));
+
+    let item: ItemTrait = parse_quote! {
+        #[async_trait]
+        trait Trait {
+            async fn f(&self);
+        }
+    };
+    assert!(syn::has_async_trait_attr(&item.attrs));
+
+    let item: ItemImpl = parse_quote! {
+        #[async_trait::async_trait]
+        impl Trait for S {
+            async fn f(&self) {}
+        }
+    };
+    assert!(syn::has_async_trait_attr(&item.attrs));
+    assert!(item.methods().next().unwrap().sig.is_async());
+
+    let method: TraitItemMethod = parse_quote!(fn f(&self););
+    assert!(!method.sig.is_async());
+}
+
+#[test]
+fn test_struct_where_clause_placement() {
+    let item = roundtrip(quote! {
+        #[derive(Debug)]
+        pub struct S<T: Copy> where T: Clone {
+            #[serde(skip)]
+            pub x: T,
+            y: u8,
+        }
+    });
+    match item {
+        Item::Struct(item) => assert!(item.is_named() && item.generics.where_clause.is_some()),
+        value => panic!("expected Item::Struct, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(struct S<T>(#[a] T) where T: Clone;));
+    match item {
+        Item::Struct(item) => assert!(item.is_tuple() && item.generics.where_clause.is_some()),
+        value => panic!("expected Item::Struct, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(struct S<T> where T: Clone;));
+    match item {
+        Item::Struct(item) => assert!(item.is_unit() && item.generics.where_clause.is_some()),
+        value => panic!("expected Item::Struct, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_trait_const_default() {
+    let item: TraitItemConst = parse_quote!(const A: u8;);
+    assert!(!item.has_default());
+    assert!(item.default_value().is_none());
+
+    let item: TraitItemConst = parse_quote!(const B: u8 = 3;);
+    assert!(item.has_default());
+    let value = item.default_value().unwrap();
+    assert_eq!(value.to_token_stream().to_string(), "3");
+}
+
+#[test]
+fn test_merge_use_items() {
+    fn merge(items: Vec<ItemUse>) -> Vec<String> {
+        syn::merge_use_items(&items)
+            .iter()
+            .map(|item| item.to_token_stream().to_string())
+            .collect()
+    }
+
+    let merged = merge(vec![parse_quote!(use a::b;), parse_quote!(use a::c;)]);
+    assert_eq!(merged, [quote!(use a::{b, c};).to_string()]);
+
+    let merged = merge(vec![
+        parse_quote!(use a::b as c;),
+        parse_quote!(use x::y;),
+        parse_quote!(use a::d::*;),
+        parse_quote!(use a::{d::e, b as c};),
+    ]);
+    let expected = [
+        quote!(use a::{b as c, d::{*, e}};).to_string(),
+        quote!(use x::y;).to_string(),
+    ];
+    assert_eq!(merged, expected);
+
+    let merged = merge(vec![
+        parse_quote!(use a::b;),
+        parse_quote!(#[cfg(test)] use a::c;),
+        parse_quote!(pub use a::d;),
+    ]);
+    let expected = [
+        quote!(use a::b;).to_string(),
+        quote!(#[cfg(test)] use a::c;).to_string(),
+        quote!(pub use a::d;).to_string(),
+    ];
+    assert_eq!(merged, expected);
+}
+
+#[test]
+fn test_cached_item() {
+    let mut cached = CachedItem::new(parse_quote!(fn f() {}));
+    assert!(!cached.is_cached());
+    let expected = quote!(fn f() {}).to_string();
+    assert_eq!(cached.tokens().to_string(), expected);
+    assert!(cached.is_cached());
+    assert_eq!(cached.to_token_stream().to_string(), expected);
+
+    let vis = cached.item_mut().vis_mut().unwrap();
+    *vis = parse_quote!(pub(crate));
+    assert!(!cached.is_cached());
+    let expected = quote!(pub(crate) fn f() {}).to_string();
+    assert_eq!(cached.to_token_stream().to_string(), expected);
+    assert!(cached.is_cached());
+    assert_eq!(cached.tokens().to_string(), expected);
+}
+
+#[test]
+fn test_required_and_provided_methods() {
+    let item: ItemTrait = parse_quote! {
+        trait Trait {
+            const C: u8;
+            fn required(&self);
+            fn provided(&self) {}
+        }
+    };
+    let required: Vec<_> = item.required_methods().map(|m| m.sig.ident.to_string()).collect();
+    assert_eq!(required, ["required"]);
+    let provided: Vec<_> = item.provided_methods().map(|m| m.sig.ident.to_string()).collect();
+    assert_eq!(provided, ["provided"]);
+}
+
+#[test]
+fn test_impl_const_trait() {
+    let item = roundtrip(quote!(impl const Trait for T {}));
+    match item {
+        Item::Impl(item) => {
+            assert!(item.constness.is_some());
+            assert!(item.trait_.is_some());
+        }
+        value => panic!("expected Item::Impl, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(impl<T> const Trait for S<T> where T: Copy {}));
+    match item {
+        Item::Impl(item) => assert!(item.constness.is_some()),
+        value => panic!("expected Item::Impl, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(impl Trait for T {}));
+    match item {
+        Item::Impl(item) => assert!(item.constness.is_none()),
+        value => panic!("expected Item::Impl, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_foreign_item_doc_attrs() {
+    let item: ItemForeignMod = parse_quote! {
+        extern "C" {
+            /// Prints a formatted string.
+            fn printf(format: *const c_char, ...) -> c_int;
+        }
+    };
+    assert!(item.attrs.is_empty());
+    match &item.items[0] {
+        ForeignItem::Fn(item) => {
+            assert_eq!(syn::collect_doc(&item.attrs), "Prints a formatted string.");
+        }
+        value => panic!("expected ForeignItem::Fn, got {:?}", value),
+    }
+
+    let item: ItemForeignMod = parse_quote! {
+        /// The C library.
+        extern "C" {
+            #![allow(dead_code)]
+            /// Exits the process.
+            fn exit(status: c_int) -> !;
+        }
+    };
+    assert_eq!(syn::collect_doc(&item.attrs), "The C library.");
+    assert_eq!(item.attrs.len(), 2);
+    match &item.items[0] {
+        ForeignItem::Fn(item) => assert_eq!(syn::collect_doc(&item.attrs), "Exits the process."),
+        value => panic!("expected ForeignItem::Fn, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_union_fields() {
+    let item: ItemUnion = parse_quote!(union U { a: u8, b: u32 });
+    let idents: Vec<_> = item.field_idents().map(Ident::to_string).collect();
+    assert_eq!(idents, ["a", "b"]);
+    let field = item.field("b").unwrap();
+    assert_eq!(field.ty.to_token_stream().to_string(), "u32");
+    assert!(item.field("c").is_none());
+
+    let item = roundtrip(quote! {
+        #[repr(C)]
+        pub union U {
+            a: u8,
+            pub b: ManuallyDrop<String>,
+        }
+    });
+    match item {
+        Item::Union(item) => assert_eq!(item.field_idents().count(), 2),
+        value => panic!("expected Item::Union, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_signature_arity() {
+    let method: TraitItemMethod = parse_quote!(fn f(&self, a: u8, b: u8););
+    assert_eq!(method.sig.arity(), 2);
+    assert!(method.sig.has_receiver());
+
+    let item: ItemFn = parse_quote!(unsafe extern "C" fn g(a: u8, args: ...) {});
+    assert_eq!(item.sig.arity(), 1);
+    assert!(!item.sig.has_receiver());
+
+    let item: ForeignItemFn = parse_quote!(fn g(a: u8, ...););
+    assert_eq!(item.sig.arity(), 1);
+    assert!(!item.sig.has_receiver());
+}
+
+#[test]
+fn test_assoc_type_bindings_in_bounds() {
+    fn binding_idents(bounds: &Punctuated<TypeParamBound, Token![+]>) -> Vec<String> {
+        let mut idents = Vec::new();
+        for bound in bounds {
+            let trait_bound = match bound {
+                TypeParamBound::Trait(trait_bound) => trait_bound,
+                TypeParamBound::Lifetime(_) => continue,
+            };
+            let segment = trait_bound.path.segments.last().unwrap();
+            if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                for arg in &arguments.args {
+                    match arg {
+                        GenericArgument::Binding(binding) => idents.push(binding.ident.to_string()),
+                        GenericArgument::Constraint(constraint) => {
+                            idents.push(constraint.ident.to_string())
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        idents
+    }
+
+    let item = roundtrip(quote!(trait A = Iterator<Item = u8>;));
+    match item {
+        Item::TraitAlias(item) => assert_eq!(binding_idents(&item.bounds), ["Item"]),
+        value => panic!("expected Item::TraitAlias, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(trait B<T> = IntoIterator<Item = T, IntoIter: Send> + 'static;));
+    match item {
+        Item::TraitAlias(item) => assert_eq!(binding_idents(&item.bounds), ["Item", "IntoIter"]),
+        value => panic!("expected Item::TraitAlias, got {:?}", value),
+    }
+
+    let item = roundtrip(quote! {
+        pub trait Bytes: Iterator<Item = u8> + Deref<Target = [u8]> {}
+    });
+    match item {
+        Item::Trait(item) => assert_eq!(binding_idents(&item.supertraits), ["Item", "Target"]),
+        value => panic!("expected Item::Trait, got {:?}", value),
+    }
+}
+
+#[test]
+#[cfg(feature = "span-locations")]
+fn test_parse_items_with_spans() {
+    let src = "/// Ünïcode docs.\nstruct S;\n\nfn f() {\n    let _ = \"é\";\n}\n";
+    let items = syn::parse_items_with_spans(src).unwrap();
+    assert_eq!(items.len(), 2);
+
+    let (_, first) = &items[0];
+    let (_, second) = &items[1];
+    assert_eq!(&src[first.clone()], "/// Ünïcode docs.\nstruct S;");
+    assert_eq!(&src[second.clone()], "fn f() {\n    let _ = \"é\";\n}");
+    assert!(first.end <= second.start);
+}
+
+#[test]
+fn test_type_alias_where_clause() {
+    let item = roundtrip(quote!(type Foo<T> where T: Send = Bar<T>;));
+    match item {
+        Item::Type(item) => {
+            assert!(item.generics.where_clause.is_some());
+            assert!(item.where_clause.is_none());
+        }
+        value => panic!("expected Item::Type, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(pub type Foo<T> = Bar<T> where T: Send;));
+    match item {
+        Item::Type(item) => {
+            assert!(item.generics.where_clause.is_none());
+            let where_clause = item.where_clause.unwrap();
+            assert_eq!(where_clause.predicates.len(), 1);
+        }
+        value => panic!("expected Item::Type, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_has_macro() {
+    fn path<T: HasMacro>(item: &T) -> String {
+        item.mac().path.to_token_stream().to_string()
+    }
+
+    let item: ItemMod = parse_quote! {
+        mod m {
+            thread_local! { static X: u8 = 0; }
+            extern "C" {
+                declare_fns!();
+            }
+            trait Trait {
+                trait_items!();
+            }
+            impl Trait for S {
+                todo!();
+            }
+        }
+    };
+
+    let mut paths = Vec::new();
+    for item in &item.content.as_ref().unwrap().1 {
+        match item {
+            Item::Macro(item) => paths.push(path(item)),
+            Item::ForeignMod(item) => {
+                for item in &item.items {
+                    if let ForeignItem::Macro(item) = item {
+                        paths.push(path(item));
+                    }
+                }
+            }
+            Item::Trait(item) => {
+                for item in &item.items {
+                    if let TraitItem::Macro(item) = item {
+                        paths.push(path(item));
+                    }
+                }
+            }
+            Item::Impl(item) => {
+                for item in &item.items {
+                    if let ImplItem::Macro(item) = item {
+                        assert!(item.mac().path.is_ident("todo"));
+                        paths.push(path(item));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(paths, ["thread_local", "declare_fns", "trait_items", "todo"]);
+}
+
+#[test]
+fn test_impl_self_ty_ident() {
+    let item: ItemImpl = parse_quote!(impl Foo {});
+    assert_eq!(item.self_ty_ident().unwrap(), "Foo");
+
+    let item: ItemImpl = parse_quote!(impl<T> Bar<T> {});
+    assert_eq!(item.self_ty_ident().unwrap(), "Bar");
+
+    let item: ItemImpl = parse_quote!(impl Trait for crate::a::Baz {});
+    assert_eq!(item.self_ty_ident().unwrap(), "Baz");
+
+    let item: ItemImpl = parse_quote!(impl (A, B) {});
+    assert!(item.self_ty_ident().is_none());
+
+    let item: ItemImpl = parse_quote!(impl<'a> Trait for &'a Foo {});
+    assert!(item.self_ty_ident().is_none());
+
+    let item: ItemImpl = parse_quote!(impl<T: Trait> Other for <T as Trait>::Assoc {});
+    assert!(item.self_ty_ident().is_none());
+}
+
+#[test]
+fn test_explicit_discriminants() {
+    let item: ItemEnum = parse_quote! {
+        #[repr(u8)]
+        enum E {
+            A = 1,
+            B,
+            C = 10,
+        }
+    };
+    let discriminants: Vec<_> = item
+        .explicit_discriminants()
+        .into_iter()
+        .map(|(ident, expr)| (ident.to_string(), expr.map(|expr| quote!(#expr).to_string())))
+        .collect();
+    assert_eq!(
+        discriminants,
+        [
+            ("A".to_owned(), Some("1".to_owned())),
+            ("B".to_owned(), None),
+            ("C".to_owned(), Some("10".to_owned())),
+        ]
+    );
+}
+
+#[test]
+fn test_to_pretty_string() {
+    let item: Item = parse_quote! {
+        #[inline]
+        fn f(x: u8) -> u8 {
+            let y = x + 1;
+            if y > 2 { y } else { 0 }
+        }
+    };
+    let expected = [
+        "# [inline]",
+        "fn f (x : u8) -> u8 {",
+        "    let y = x + 1 ;",
+        "    if y > 2 {",
+        "        y",
+        "    } else {",
+        "        0",
+        "    }",
+        "}",
+    ];
+    assert_eq!(item.to_pretty_string(), expected.join("\n"));
+
+    let item: Item = parse_quote! {
+        mod m {
+            struct S;
+            impl S {}
+        }
+    };
+    assert_eq!(item.to_pretty_string(), "mod m {\n    struct S ;\n    impl S {}\n}");
+}
+
+#[test]
+fn test_trait_item_type_default_and_bounds() {
+    let item: TraitItemType = parse_quote!(type Item: Clone = u8;);
+    let bounds: Vec<_> = item.bounds_iter().map(|bound| quote!(#bound).to_string()).collect();
+    assert_eq!(bounds, ["Clone"]);
+    let ty = item.default_type().unwrap();
+    assert_eq!(quote!(#ty).to_string(), "u8");
+
+    let item: TraitItemType = parse_quote!(type Item;);
+    assert_eq!(item.bounds_iter().count(), 0);
+    assert!(item.default_type().is_none());
+}
+
+#[test]
+fn test_item_doc() {
+    let item: Item = parse_quote! {
+        /// A point.
+        ///
+        /// In pixels.
+        #[derive(Copy, Clone)]
+        struct Point(u32, u32);
+    };
+    assert_eq!(item.doc().unwrap(), "A point.\n\nIn pixels.");
+
+    let item: Item = parse_quote!(fn f() {});
+    assert!(item.doc().is_none());
+
+    let item: Item = parse_quote! {
+        #[doc(hidden)]
+        #[inline]
+        pub fn g() {}
+    };
+    assert!(item.doc().is_none());
+}
+
+#[test]
+fn test_const_block_initializer() {
+    let item = roundtrip(quote!(const X: u8 = { 1 };));
+    match item {
+        Item::Const(item) => match *item.expr {
+            Expr::Block(_) => {}
+            value => panic!("expected Expr::Block, got {:?}", value),
+        },
+        value => panic!("expected Item::Const, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(static X: [u8; 2] = { let x = 1; [x, x] };));
+    match item {
+        Item::Static(item) => match *item.expr {
+            Expr::Block(_) => {}
+            value => panic!("expected Expr::Block, got {:?}", value),
+        },
+        value => panic!("expected Item::Static, got {:?}", value),
+    }
+
+    // Inline const blocks are not an expression in this version, so they are
+    // rejected rather than parsed into a different initializer.
+    let err = syn::parse2::<Item>(quote!(const X: u8 = const { 1 + 1 };)).unwrap_err();
+    assert_eq!(err.to_string(), "expected expression");
+    let err = syn::parse2::<Item>(quote!(static X: u8 = const { 1 + 1 };)).unwrap_err();
+    assert_eq!(err.to_string(), "expected expression");
+}
+
+#[test]
+fn test_is_entrypoint() {
+    let item: ItemFn = parse_quote!(fn main() {});
+    assert!(item.is_entrypoint());
+    assert!(!item.has_start_attr());
+
+    let item: ItemFn = parse_quote!(fn main(x: u8) {});
+    assert!(item.is_entrypoint());
+
+    let item: ItemFn = parse_quote!(fn notmain() {});
+    assert!(!item.is_entrypoint());
+
+    let item: ItemFn = parse_quote! {
+        #[start]
+        fn start(argc: isize, argv: *const *const u8) -> isize {
+            0
+        }
+    };
+    assert!(!item.is_entrypoint());
+    assert!(item.has_start_attr());
+}
+
+#[test]
+fn test_has_where_clause() {
+    let item: ItemImpl = parse_quote!(impl<T> S<T> where T: Copy {});
+    assert!(item.has_where_clause());
+    let item: ItemImpl = parse_quote!(impl<T: Copy> S<T> {});
+    assert!(!item.has_where_clause());
+
+    let item: ItemFn = parse_quote!(fn f<T>() where T: Copy {});
+    assert!(item.has_where_clause());
+    let item: ItemFn = parse_quote!(fn f<T: Copy>() {});
+    assert!(!item.has_where_clause());
+
+    let item: ItemStruct = parse_quote!(struct S<T>(T) where T: Copy;);
+    assert!(item.has_where_clause());
+    let item: ItemStruct = parse_quote!(struct S<T> { t: T });
+    assert!(!item.has_where_clause());
+
+    let item: ItemTrait = parse_quote!(trait Tr<T> where T: Copy {});
+    assert!(item.has_where_clause());
+    let item: ItemTrait = parse_quote!(trait Tr<T> {});
+    assert!(!item.has_where_clause());
+
+    let item: ItemType = parse_quote!(type A<T> where T: Copy = T;);
+    assert!(item.has_where_clause());
+    let item: ItemType = parse_quote!(type A<T> = T where T: Copy;);
+    assert!(item.has_where_clause());
+    let item: ItemType = parse_quote!(type A<T> = T;);
+    assert!(!item.has_where_clause());
+
+    let item: ItemEnum = parse_quote!(enum E<T> where T: Copy { A(T) });
+    assert!(item.has_where_clause());
+    let item: ItemEnum = parse_quote!(enum E<T> { A(T) });
+    assert!(!item.has_where_clause());
+}
+
+#[test]
+fn test_raw_ident_fn_names() {
+    for tokens in &[quote!(fn r#type() {}), quote!(fn r#async() {}), quote!(fn r#gen() {})] {
+        match roundtrip(tokens.clone()) {
+            Item::Fn(item) => assert!(item.sig.ident.to_string().starts_with("r#")),
+            value => panic!("expected Item::Fn, got {:?}", value),
+        }
+    }
+
+    let item = roundtrip(quote! {
+        trait Trait {
+            fn r#fn(&self);
+        }
+    });
+    match item {
+        Item::Trait(item) => match &item.items[0] {
+            TraitItem::Method(method) => {
+                assert_eq!(method.sig.ident.to_string(), "r#fn");
+                assert!(method.sig.has_receiver());
+            }
+            value => panic!("expected TraitItem::Method, got {:?}", value),
+        },
+        value => panic!("expected Item::Trait, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_fn_arg_constructors() {
+    let mut sig = Signature::default();
+    sig.ident = Ident::new("f", Span::call_site());
+    sig.inputs.push(FnArg::receiver_ref(true));
+    sig.inputs.push(FnArg::typed(parse_quote!(x), parse_quote!(u8)));
+    assert_eq!(sig.to_token_stream().to_string(), quote!(fn f(&mut self, x: u8)).to_string());
+    assert!(sig.has_receiver());
+    assert_eq!(sig.arity(), 1);
+
+    let arg = FnArg::receiver_ref(false);
+    assert_eq!(arg.to_token_stream().to_string(), quote!(&self).to_string());
+}
+
+#[test]
+fn test_variadic_attrs() {
+    let item = roundtrip(quote! {
+        extern "C" {
+            fn f(x: u8, #[attr] ...);
+        }
+    });
+    match item {
+        Item::ForeignMod(item) => match &item.items[0] {
+            ForeignItem::Fn(item) => {
+                let variadic = item.sig.variadic.as_ref().unwrap();
+                assert_eq!(variadic.attrs.len(), 1);
+                assert!(variadic.attrs[0].path.is_ident("attr"));
+                assert_eq!(item.sig.variadic_attrs().len(), 1);
+            }
+            value => panic!("expected ForeignItem::Fn, got {:?}", value),
+        },
+        value => panic!("expected Item::ForeignMod, got {:?}", value),
+    }
+
+    let item = roundtrip(quote!(unsafe extern "C" fn f(x: u8, #[attr] args: ...) {}));
+    match item {
+        Item::Fn(item) => {
+            assert!(item.sig.variadic.as_ref().unwrap().attrs.is_empty());
+            let attrs = item.sig.variadic_attrs();
+            assert_eq!(attrs.len(), 1);
+            assert!(attrs[0].path.is_ident("attr"));
+
+            let item: ItemFn = parse_quote!(fn f(x: u8) {});
+            assert!(item.sig.variadic_attrs().is_empty());
+        }
+        value => panic!("expected Item::Fn, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_supertrait_paths() {
+    let item: ItemTrait = parse_quote!(trait A: B + 'static + C<u8> {});
+    let paths: Vec<_> = item.supertrait_paths().map(|path| quote!(#path).to_string()).collect();
+    assert_eq!(paths, ["B", &quote!(C<u8>).to_string()]);
+
+    let item: ItemTrait = parse_quote!(trait A: 'static {});
+    assert_eq!(item.supertrait_paths().count(), 0);
+}
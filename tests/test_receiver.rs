@@ -1,6 +1,12 @@
 mod features;
 
-use syn::{FnArg, Receiver, TraitItemMethod};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::ToTokens;
+use syn::visit::{self, Visit};
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    FnArg, ItemFn, PartialBorrow, PartialBorrows, Receiver, Reference, Signature, TraitItemMethod,
+};
 
 #[test]
 fn test_by_value() {
@@ -61,8 +67,7 @@ fn test_value_shorthand() {
     let TraitItemMethod { sig, .. } = syn::parse_quote!(fn value_shorthand(self););
     match sig.receiver() {
         Some(FnArg::Receiver(Receiver {
-            reference: None,
-            mutability: None,
+            reference: Reference::None(None),
             ..
         })) => (),
         value => panic!("expected FnArg::Receiver without ref/mut, got {:?}", value),
@@ -74,8 +79,7 @@ fn test_mut_value_shorthand() {
     let TraitItemMethod { sig, .. } = syn::parse_quote!(fn mut_value_shorthand(mut self););
     match sig.receiver() {
         Some(FnArg::Receiver(Receiver {
-            reference: None,
-            mutability: Some(_),
+            reference: Reference::None(Some(_)),
             ..
         })) => (),
         value => panic!("expected FnArg::Receiver with mut, got {:?}", value),
@@ -87,8 +91,7 @@ fn test_ref_shorthand() {
     let TraitItemMethod { sig, .. } = syn::parse_quote!(fn ref_shorthand(&self););
     match sig.receiver() {
         Some(FnArg::Receiver(Receiver {
-            reference: Some(_),
-            mutability: None,
+            reference: Reference::Full(_, _, None),
             ..
         })) => (),
         value => panic!("expected FnArg::Receiver with ref, got {:?}", value),
@@ -100,10 +103,387 @@ fn test_ref_mut_shorthand() {
     let TraitItemMethod { sig, .. } = syn::parse_quote!(fn ref_mut_shorthand(&mut self););
     match sig.receiver() {
         Some(FnArg::Receiver(Receiver {
-            reference: Some(_),
-            mutability: Some(_),
+            reference: Reference::Full(_, _, Some(_)),
             ..
         })) => (),
         value => panic!("expected FnArg::Receiver with ref+mut, got {:?}", value),
     }
 }
+
+#[test]
+fn test_partial_borrow() {
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn partial(self.{a, mut b}););
+    match sig.receiver() {
+        Some(FnArg::Receiver(Receiver {
+            reference: Reference::Partial(..),
+            ..
+        })) => (),
+        value => panic!("expected FnArg::Receiver with partial borrows, got {:?}", value),
+    }
+}
+
+#[test]
+fn test_borrowed_field_count() {
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn partial(self.{a, b, c}););
+    assert_eq!(sig.borrowed_field_count(), Some(3));
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn by_ref(&self););
+    assert_eq!(sig.borrowed_field_count(), None);
+
+    let ItemFn { sig, .. } = syn::parse_quote!(fn free(a: u8) {});
+    assert_eq!(sig.borrowed_field_count(), None);
+}
+
+#[test]
+fn test_visit_partial_borrows() {
+    struct CollectBorrows(Vec<(String, bool)>);
+
+    impl<'ast> Visit<'ast> for CollectBorrows {
+        fn visit_partial_borrow(&mut self, borrow: &'ast PartialBorrow) {
+            let name = borrow.ident.to_string();
+            self.0.push((name, borrow.mutability.is_some()));
+            visit::visit_partial_borrow(self, borrow);
+        }
+    }
+
+    let ItemFn { sig, .. } = syn::parse_quote!(fn partial(self.{a, mut b}) {});
+    let mut collect = CollectBorrows(Vec::new());
+    collect.visit_signature(&sig);
+    let expected = vec![("a".to_owned(), false), ("b".to_owned(), true)];
+    assert_eq!(collect.0, expected);
+}
+
+#[test]
+fn test_visit_mut_partial_borrows() {
+    struct MakeMut;
+
+    impl VisitMut for MakeMut {
+        fn visit_partial_borrow_mut(&mut self, borrow: &mut PartialBorrow) {
+            if borrow.mutability.is_none() {
+                borrow.mutability = Some(Default::default());
+            }
+            visit_mut::visit_partial_borrow_mut(self, borrow);
+        }
+    }
+
+    let TraitItemMethod { mut sig, .. } = syn::parse_quote!(fn partial(self.{a, mut b}););
+    MakeMut.visit_signature_mut(&mut sig);
+    let TraitItemMethod { sig: expected, .. } = syn::parse_quote!(fn partial(self.{mut a, mut b}););
+    assert_eq!(sig, expected);
+}
+
+#[test]
+#[cfg(feature = "span-locations")]
+fn test_partial_borrows_brace_span() {
+    use proc_macro2::{Delimiter, TokenTree};
+    use syn::punctuated::Punctuated;
+    use syn::token;
+
+    let source: TokenStream = "\n    {}".parse().unwrap();
+    let span = source.into_iter().next().unwrap().span();
+
+    let mut borrows = Punctuated::new();
+    borrows.push(PartialBorrow {
+        mutability: None,
+        ident: Ident::new("a", Span::call_site()),
+    });
+    let partial = PartialBorrows {
+        mutability: None,
+        brace_token: token::Brace(span),
+        borrows,
+    };
+
+    let group = match partial.into_token_stream().into_iter().next() {
+        Some(TokenTree::Group(group)) => group,
+        value => panic!("expected a group, got {:?}", value),
+    };
+    assert_eq!(group.delimiter(), Delimiter::Brace);
+    assert_eq!(group.span().start(), span.start());
+    assert_eq!(group.span().end(), span.end());
+    assert_ne!(group.span().start(), Span::call_site().start());
+}
+
+#[test]
+fn test_is_self() {
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self, x: u8););
+    assert!(sig.inputs[0].is_self());
+    assert!(!sig.inputs[1].is_self());
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self: Box<Self>););
+    assert!(sig.inputs[0].is_self());
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self: Pin<&mut Self>););
+    assert!(sig.inputs[0].is_self());
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(mut self: Pin<&mut Self>););
+    assert!(sig.inputs[0].is_self());
+}
+
+#[test]
+fn test_partial_borrow_with_ref() {
+    let err = syn::parse_str::<TraitItemMethod>("fn f(&self.{a});").unwrap_err();
+    assert_eq!(err.to_string(), "partial borrows cannot be combined with `&` receivers");
+
+    let err = syn::parse_str::<TraitItemMethod>("fn f(&'a mut self.{a});").unwrap_err();
+    assert_eq!(err.to_string(), "partial borrows cannot be combined with `&` receivers");
+
+    syn::parse_str::<TraitItemMethod>("fn f(&self);").unwrap();
+    syn::parse_str::<TraitItemMethod>("fn f(self.{a});").unwrap();
+}
+
+#[test]
+fn test_partial_borrow_with_typed_self() {
+    let err = syn::parse_str::<TraitItemMethod>("fn f(self: Box<Self>.{a});").unwrap_err();
+    assert_eq!(err.to_string(), "partial borrows cannot be combined with typed `self` receivers");
+
+    let err = syn::parse_str::<ItemFn>("fn f(mut self: Self.{a, mut b}) {}").unwrap_err();
+    assert_eq!(err.to_string(), "partial borrows cannot be combined with typed `self` receivers");
+
+    syn::parse_str::<TraitItemMethod>("fn f(self: Box<Self>);").unwrap();
+}
+
+#[test]
+fn test_group_mut_partial_borrow() {
+    fn partial(sig: &Signature) -> &PartialBorrows {
+        match sig.receiver() {
+            Some(FnArg::Receiver(Receiver {
+                reference: Reference::Partial(_, partial),
+                ..
+            })) => partial,
+            value => panic!("expected FnArg::Receiver with partial borrows, got {:?}", value),
+        }
+    }
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self.mut{a, b}););
+    let borrows = partial(&sig);
+    assert!(borrows.is_group_mut());
+    assert!(borrows.borrows.iter().all(|borrow| borrow.mutability.is_none()));
+    assert_eq!(sig.to_token_stream().to_string(), "fn f (self . mut { a , b })");
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self.{mut a, b}););
+    let borrows = partial(&sig);
+    assert!(!borrows.is_group_mut());
+    assert!(borrows.borrows[0].mutability.is_some());
+    assert!(borrows.borrows[1].mutability.is_none());
+    assert_eq!(sig.to_token_stream().to_string(), "fn f (self . { mut a , b })");
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self.{a}););
+    let borrows = partial(&sig);
+    assert!(!borrows.is_group_mut());
+    assert_eq!(sig.to_token_stream().to_string(), "fn f (self . { a })");
+}
+
+#[test]
+fn test_typed_args() {
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(&self, x: u8, (a, b): (i8, i16)););
+    let typed: Vec<_> = sig
+        .inputs
+        .iter()
+        .filter_map(FnArg::as_typed)
+        .map(|(pat, ty)| {
+            let pat = pat.to_token_stream().to_string();
+            (pat, ty.to_token_stream().to_string())
+        })
+        .collect();
+    let expected = vec![
+        ("x".to_owned(), "u8".to_owned()),
+        ("(a , b)".to_owned(), "(i8 , i16)".to_owned()),
+    ];
+    assert_eq!(typed, expected);
+
+    let mut inputs = sig.inputs.into_iter();
+    assert!(inputs.next().unwrap().into_typed().is_none());
+    let typed = inputs.next().unwrap().into_typed().unwrap();
+    assert_eq!(typed.pat.to_token_stream().to_string(), "x");
+}
+
+#[test]
+fn test_construct_partial_borrows() {
+    let a = Ident::new("a", Span::call_site());
+    let b = Ident::new("b", Span::call_site());
+    let mutable = PartialBorrow::mutable(b);
+    #[cfg(feature = "span-locations")]
+    assert_eq!(mutable.mutability.unwrap().span.start(), mutable.ident.span().start());
+
+    let partial: PartialBorrows = vec![PartialBorrow::shared(a), mutable].into_iter().collect();
+    let receiver = Receiver {
+        attrs: Vec::new(),
+        reference: Reference::Partial(Default::default(), partial),
+        self_token: Default::default(),
+    };
+    let expected: TokenStream = "self.{a, mut b}".parse().unwrap();
+    assert_eq!(receiver.into_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_reference_prefix_tokens() {
+    fn prefix(method: TraitItemMethod) -> String {
+        let mut tokens = TokenStream::new();
+        match &method.sig.inputs[0] {
+            FnArg::Receiver(receiver) => receiver.reference.to_prefix_tokens(&mut tokens),
+            FnArg::Typed(_) => panic!("expected a receiver"),
+        }
+        tokens.to_string()
+    }
+
+    assert_eq!(prefix(syn::parse_quote!(fn f(self);)), "");
+    assert_eq!(prefix(syn::parse_quote!(fn f(mut self);)), "mut");
+    assert_eq!(prefix(syn::parse_quote!(fn f(&self);)), "&");
+    assert_eq!(prefix(syn::parse_quote!(fn f(&'a mut self);)), "& 'a mut");
+    let expected: TokenStream = ".{a, mut b}".parse().unwrap();
+    assert_eq!(prefix(syn::parse_quote!(fn f(self.{a, mut b});)), expected.to_string());
+}
+
+#[test]
+fn test_partial_borrows_validate_against() {
+    let known = [
+        Ident::new("a", Span::call_site()),
+        Ident::new("b", Span::call_site()),
+        Ident::new("c", Span::call_site()),
+    ];
+
+    let partial: PartialBorrows = syn::parse_quote!({a, mut c});
+    assert!(partial.validate_against(&known).is_ok());
+
+    let partial: PartialBorrows = syn::parse_str("{a,\n  foo}").unwrap();
+    let err = partial.validate_against(&known).unwrap_err();
+    assert_eq!(err.to_string(), "no field named `foo`");
+    #[cfg(feature = "span-locations")]
+    assert_eq!(err.span().start().line, 2);
+
+    let partial: PartialBorrows = syn::parse_quote!({a, mut b, mut a});
+    let err = partial.validate_against(&known).unwrap_err();
+    assert_eq!(err.to_string(), "field `a` is borrowed more than once");
+}
+
+#[test]
+fn test_partial_borrows_canonicalize() {
+    let mut method: TraitItemMethod = syn::parse_str("fn f(self.{b,\n  a});").unwrap();
+    match &mut method.sig.inputs[0] {
+        FnArg::Receiver(Receiver {
+            reference: Reference::Partial(_, partial),
+            ..
+        }) => {
+            partial.canonicalize();
+            assert_eq!(partial.borrows[0].ident, "a");
+            #[cfg(feature = "span-locations")]
+            assert_eq!(partial.borrows[0].ident.span().start().line, 2);
+        }
+        value => panic!("expected a partial receiver, got {:?}", value),
+    }
+    let expected: TokenStream = "self.{a, b}".parse().unwrap();
+    let receiver = method.sig.inputs[0].to_token_stream();
+    assert_eq!(receiver.to_string(), expected.to_string());
+
+    let mut partial: PartialBorrows = syn::parse_quote!({c, a, mut c, b,});
+    partial.canonicalize();
+    let expected: TokenStream = "{a, b, mut c,}".parse().unwrap();
+    assert_eq!(partial.into_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_fn_arg_attrs() {
+    let TraitItemMethod { mut sig, .. } = syn::parse_quote!(fn f(#[attr] a: u8, &self););
+    assert_eq!(sig.inputs[0].attrs().len(), 1);
+    assert!(sig.inputs[0].attrs()[0].path.is_ident("attr"));
+    assert!(sig.inputs[1].attrs().is_empty());
+
+    sig.inputs[1].attrs_mut().push(syn::parse_quote!(#[other]));
+    let expected: TokenStream = "#[other] &self".parse().unwrap();
+    assert_eq!(sig.inputs[1].to_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_receiver_ownership() {
+    fn receiver(method: TraitItemMethod) -> Receiver {
+        match method.sig.inputs.into_iter().next() {
+            Some(FnArg::Receiver(receiver)) => receiver,
+            value => panic!("expected a receiver, got {:?}", value),
+        }
+    }
+
+    let by_value = [
+        receiver(syn::parse_quote!(fn f(self);)),
+        receiver(syn::parse_quote!(fn f(mut self);)),
+    ];
+    for receiver in &by_value {
+        assert!(receiver.by_value() && !receiver.by_reference() && !receiver.is_partial());
+    }
+
+    let by_reference = [
+        receiver(syn::parse_quote!(fn f(&self);)),
+        receiver(syn::parse_quote!(fn f(&'a mut self);)),
+    ];
+    for receiver in &by_reference {
+        assert!(!receiver.by_value() && receiver.by_reference() && !receiver.is_partial());
+    }
+
+    let partial = receiver(syn::parse_quote!(fn f(self.{a, mut b});));
+    assert!(!partial.by_value() && !partial.by_reference() && partial.is_partial());
+}
+
+#[test]
+fn test_partial_receiver_clone_and_debug() {
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self.mut{a, b}););
+    let receiver = match &sig.inputs[0] {
+        FnArg::Receiver(receiver) => receiver,
+        value => panic!("expected a receiver, got {:?}", value),
+    };
+    let clone = receiver.clone();
+    assert_eq!(clone, *receiver);
+    assert_eq!(clone.reference, receiver.reference);
+
+    let partial = match &receiver.reference {
+        Reference::Partial(_, partial) => partial,
+        value => panic!("expected Reference::Partial, got {:?}", value),
+    };
+    assert_eq!(partial.clone(), *partial);
+    assert_eq!(partial.borrows[0].clone(), partial.borrows[0]);
+
+    let debug = format!("{:?}", partial);
+    assert!(debug.contains("PartialBorrows"));
+    assert!(debug.contains("mutability: Some"));
+    for borrow in &partial.borrows {
+        assert!(debug.contains(&format!("ident: {:?}", borrow.ident)));
+    }
+}
+
+#[test]
+fn test_receiver_parse_quote() {
+    let receiver: Receiver = syn::parse_quote!(&mut self);
+    match receiver.reference {
+        Reference::Full(_, None, Some(_)) => {}
+        ref value => panic!("expected Reference::Full, got {:?}", value),
+    }
+    let reparsed: Receiver = syn::parse2(receiver.to_token_stream()).unwrap();
+    assert_eq!(reparsed, receiver);
+
+    let receiver: Receiver = syn::parse_quote!(self.{mut a, b});
+    match &receiver.reference {
+        Reference::Partial(_, partial) => assert_eq!(partial.borrows.len(), 2),
+        value => panic!("expected Reference::Partial, got {:?}", value),
+    }
+    let tokens = receiver.to_token_stream();
+    let reparsed: Receiver = syn::parse2(tokens.clone()).unwrap();
+    assert_eq!(reparsed, receiver);
+    assert_eq!(reparsed.to_token_stream().to_string(), tokens.to_string());
+
+    let TraitItemMethod { mut sig, .. } = syn::parse_quote!(fn f(x: u8););
+    sig.inputs.insert(0, FnArg::Receiver(receiver));
+    let expected: TokenStream = "fn f(self.{mut a, b}, x: u8)".parse().unwrap();
+    assert_eq!(sig.to_token_stream().to_string(), expected.to_string());
+}
+
+#[test]
+fn test_receiver_partial_borrows() {
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn f(self.{a, mut b}, x: u8););
+    let partial = sig.receiver_partial_borrows().unwrap();
+    let idents: Vec<_> = partial.borrows.iter().map(|borrow| borrow.ident.to_string()).collect();
+    assert_eq!(idents, ["a", "b"]);
+
+    let TraitItemMethod { sig, .. } = syn::parse_quote!(fn g(&self););
+    assert!(sig.receiver_partial_borrows().is_none());
+
+    let ItemFn { sig, .. } = syn::parse_quote!(fn h(x: u8) {});
+    assert!(sig.receiver_partial_borrows().is_none());
+}